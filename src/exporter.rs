@@ -1,4 +1,4 @@
-use crate::softether_reader::SoftEtherReader;
+use crate::softether_reader::{HubSession, HubUser, IpTableEntry, SoftEtherReader};
 use anyhow::Error;
 use hyper::header::ContentType;
 use hyper::mime::{Mime, SubLevel, TopLevel};
@@ -6,12 +6,15 @@ use hyper::server::{Request, Response, Server};
 use hyper::uri::RequestUri;
 use lazy_static::lazy_static;
 use prometheus;
-use prometheus::{register_gauge_vec, Encoder, GaugeVec, TextEncoder};
+use prometheus::{
+    register_gauge_vec, register_histogram_vec, Encoder, GaugeVec, HistogramVec, TextEncoder,
+};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::Read;
 use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
 use toml;
 
 lazy_static! {
@@ -33,6 +36,14 @@ lazy_static! {
         &["hub"]
     )
     .unwrap();
+    static ref SECURE_NAT: GaugeVec =
+        register_gauge_vec!("softether_secure_nat", "SecureNAT enabled.", &["hub"]).unwrap();
+    static ref ACCESS_LISTS: GaugeVec = register_gauge_vec!(
+        "softether_access_lists",
+        "Number of access lists.",
+        &["hub"]
+    )
+    .unwrap();
     static ref USERS: GaugeVec =
         register_gauge_vec!("softether_users", "Number of users.", &["hub"]).unwrap();
     static ref GROUPS: GaugeVec =
@@ -51,6 +62,18 @@ lazy_static! {
     .unwrap();
     static ref LOGINS: GaugeVec =
         register_gauge_vec!("softether_logins", "Number of logins.", &["hub"]).unwrap();
+    static ref TCP_CONNECTIONS: GaugeVec = register_gauge_vec!(
+        "softether_tcp_connections",
+        "Number of TCP connections.",
+        &["hub"]
+    )
+    .unwrap();
+    static ref MAX_TCP_CONNECTIONS: GaugeVec = register_gauge_vec!(
+        "softether_max_tcp_connections",
+        "Maximum number of TCP connections.",
+        &["hub"]
+    )
+    .unwrap();
     static ref OUTGOING_UNICAST_PACKETS: GaugeVec = register_gauge_vec!(
         "softether_outgoing_unicast_packets",
         "Outgoing unicast transfer in packets.",
@@ -111,10 +134,717 @@ lazy_static! {
         &["hub", "user"]
     )
     .unwrap();
-    static ref USER_TRANSFER_PACKETS: GaugeVec = register_gauge_vec!(
-        "softether_user_transfer_packets",
-        "User transfer in packets.",
-        &["hub", "user"]
+    static ref USER_TRANSFER_PACKETS: GaugeVec = register_gauge_vec!(
+        "softether_user_transfer_packets",
+        "User transfer in packets.",
+        &["hub", "user"]
+    )
+    .unwrap();
+    static ref USER_TCP_CONNECTIONS: GaugeVec = register_gauge_vec!(
+        "softether_user_tcp_connections",
+        "Number of user TCP connections.",
+        &["hub", "user"]
+    )
+    .unwrap();
+    static ref USER_MAX_TCP_CONNECTIONS: GaugeVec = register_gauge_vec!(
+        "softether_user_max_tcp_connections",
+        "Maximum number of user TCP connections.",
+        &["hub", "user"]
+    )
+    .unwrap();
+
+    static ref USER_INFO: GaugeVec = register_gauge_vec!(
+        "softether_user_info",
+        "A metric with a constant '1' value labeled by group and auth type.",
+        &["hub", "user", "group", "auth_type"]
+    )
+    .unwrap();
+    static ref USER_LOGINS: GaugeVec = register_gauge_vec!(
+        "softether_user_logins",
+        "Number of user logins.",
+        &["hub", "user"]
+    )
+    .unwrap();
+    static ref USER_LAST_LOGIN_TIMESTAMP: GaugeVec = register_gauge_vec!(
+        "softether_user_last_login_timestamp_seconds",
+        "User last login time in seconds since epoch.",
+        &["hub", "user"]
+    )
+    .unwrap();
+    static ref USER_EXPIRES_TIMESTAMP: GaugeVec = register_gauge_vec!(
+        "softether_user_expires_timestamp_seconds",
+        "User expiration time in seconds since epoch.",
+        &["hub", "user"]
+    )
+    .unwrap();
+    static ref USER_TOTAL_TRANSFER_BYTES: GaugeVec = register_gauge_vec!(
+        "softether_user_total_transfer_bytes",
+        "User total transfer in bytes.",
+        &["hub", "user"]
+    )
+    .unwrap();
+    static ref USER_TOTAL_TRANSFER_PACKETS: GaugeVec = register_gauge_vec!(
+        "softether_user_total_transfer_packets",
+        "User total transfer in packets.",
+        &["hub", "user"]
+    )
+    .unwrap();
+
+    static ref SESSION_TRANSFER_BYTES: GaugeVec = register_gauge_vec!(
+        "softether_session_transfer_bytes",
+        "Session transfer in bytes.",
+        &["hub", "session", "user", "source", "location"]
+    )
+    .unwrap();
+    static ref SESSION_TRANSFER_PACKETS: GaugeVec = register_gauge_vec!(
+        "softether_session_transfer_packets",
+        "Session transfer in packets.",
+        &["hub", "session", "user", "source", "location"]
+    )
+    .unwrap();
+    static ref SESSION_TCP_CONNECTIONS: GaugeVec = register_gauge_vec!(
+        "softether_session_tcp_connections",
+        "Number of session TCP connections.",
+        &["hub", "session", "user", "source", "location"]
+    )
+    .unwrap();
+    static ref SESSION_MAX_TCP_CONNECTIONS: GaugeVec = register_gauge_vec!(
+        "softether_session_max_tcp_connections",
+        "Maximum number of session TCP connections.",
+        &["hub", "session", "user", "source", "location"]
+    )
+    .unwrap();
+
+    static ref SESSION_INFO: GaugeVec = register_gauge_vec!(
+        "softether_session_info",
+        "A metric with a constant '1' value labeled by session details.",
+        &[
+            "hub",
+            "session",
+            "user",
+            "client_ip",
+            "client_reported_ip",
+            "virtual_ip",
+            "client_hostname",
+            "client_product",
+            "client_version",
+            "client_os",
+            "cipher",
+            "compression",
+            "udp_acceleration",
+            "half_duplex"
+        ]
+    )
+    .unwrap();
+    static ref SESSION_START_TIME: GaugeVec = register_gauge_vec!(
+        "softether_session_start_time_seconds",
+        "Session start time in seconds since epoch.",
+        &["hub", "session", "user"]
+    )
+    .unwrap();
+
+    static ref MAC_TABLE_ENTRY: GaugeVec = register_gauge_vec!(
+        "softether_mac_table_entry",
+        "A metric with a constant '1' value labeled by MAC table entry.",
+        &["hub", "session", "user", "mac", "vlan"]
+    )
+    .unwrap();
+    static ref MAC_TABLE_CREATED_TIMESTAMP: GaugeVec = register_gauge_vec!(
+        "softether_mac_table_created_timestamp_seconds",
+        "MAC table entry creation time in seconds since epoch.",
+        &["hub", "session", "user", "mac", "vlan"]
+    )
+    .unwrap();
+    static ref MAC_TABLE_UPDATED_TIMESTAMP: GaugeVec = register_gauge_vec!(
+        "softether_mac_table_updated_timestamp_seconds",
+        "MAC table entry update time in seconds since epoch.",
+        &["hub", "session", "user", "mac", "vlan"]
+    )
+    .unwrap();
+    static ref IP_TABLE_ENTRY: GaugeVec = register_gauge_vec!(
+        "softether_ip_table_entry",
+        "A metric with a constant '1' value labeled by IP table entry.",
+        &["hub", "session", "user", "ip", "dhcp"]
+    )
+    .unwrap();
+    static ref IP_TABLE_CREATED_TIMESTAMP: GaugeVec = register_gauge_vec!(
+        "softether_ip_table_created_timestamp_seconds",
+        "IP table entry creation time in seconds since epoch.",
+        &["hub", "session", "user", "ip", "dhcp"]
+    )
+    .unwrap();
+    static ref IP_TABLE_UPDATED_TIMESTAMP: GaugeVec = register_gauge_vec!(
+        "softether_ip_table_updated_timestamp_seconds",
+        "IP table entry update time in seconds since epoch.",
+        &["hub", "session", "user", "ip", "dhcp"]
+    )
+    .unwrap();
+
+    static ref CASCADE_INFO: GaugeVec = register_gauge_vec!(
+        "softether_cascade_info",
+        "A metric with a constant '1' value labeled by destination server and hub.",
+        &["hub", "cascade", "server", "remote_hub"]
+    )
+    .unwrap();
+    static ref CASCADE_UP: GaugeVec = register_gauge_vec!(
+        "softether_cascade_up",
+        "Cascade connection is established.",
+        &["hub", "cascade"]
+    )
+    .unwrap();
+    static ref CASCADE_START_TIME: GaugeVec = register_gauge_vec!(
+        "softether_cascade_start_time_seconds",
+        "Cascade connection start time in seconds since epoch.",
+        &["hub", "cascade"]
+    )
+    .unwrap();
+    static ref CASCADE_FIRST_ESTABLISHED_TIMESTAMP: GaugeVec = register_gauge_vec!(
+        "softether_cascade_first_established_timestamp_seconds",
+        "First session established time in seconds since epoch.",
+        &["hub", "cascade"]
+    )
+    .unwrap();
+    static ref CASCADE_ESTABLISHED_TIMESTAMP: GaugeVec = register_gauge_vec!(
+        "softether_cascade_established_timestamp_seconds",
+        "Current session established time in seconds since epoch.",
+        &["hub", "cascade"]
+    )
+    .unwrap();
+    static ref CASCADE_ESTABLISHED_COUNT: GaugeVec = register_gauge_vec!(
+        "softether_cascade_established_count",
+        "Number of times the cascade session has been established.",
+        &["hub", "cascade"]
+    )
+    .unwrap();
+    static ref CASCADE_TCP_CONNECTIONS: GaugeVec = register_gauge_vec!(
+        "softether_cascade_tcp_connections",
+        "Number of cascade TCP connections.",
+        &["hub", "cascade"]
+    )
+    .unwrap();
+    static ref CASCADE_MAX_TCP_CONNECTIONS: GaugeVec = register_gauge_vec!(
+        "softether_cascade_max_tcp_connections",
+        "Maximum number of cascade TCP connections.",
+        &["hub", "cascade"]
+    )
+    .unwrap();
+    static ref CASCADE_OUTGOING_BYTES: GaugeVec = register_gauge_vec!(
+        "softether_cascade_outgoing_bytes",
+        "Cascade outgoing transfer in bytes.",
+        &["hub", "cascade"]
+    )
+    .unwrap();
+    static ref CASCADE_INCOMING_BYTES: GaugeVec = register_gauge_vec!(
+        "softether_cascade_incoming_bytes",
+        "Cascade incoming transfer in bytes.",
+        &["hub", "cascade"]
+    )
+    .unwrap();
+    static ref CASCADE_OUTGOING_PACKETS: GaugeVec = register_gauge_vec!(
+        "softether_cascade_outgoing_packets",
+        "Cascade outgoing transfer in packets.",
+        &["hub", "cascade"]
+    )
+    .unwrap();
+    static ref CASCADE_INCOMING_PACKETS: GaugeVec = register_gauge_vec!(
+        "softether_cascade_incoming_packets",
+        "Cascade incoming transfer in packets.",
+        &["hub", "cascade"]
+    )
+    .unwrap();
+
+    static ref DHCP_ENABLED: GaugeVec = register_gauge_vec!(
+        "softether_dhcp_enabled",
+        "Virtual DHCP server enabled.",
+        &["hub"]
+    )
+    .unwrap();
+    static ref DHCP_POOL_SIZE: GaugeVec = register_gauge_vec!(
+        "softether_dhcp_pool_size",
+        "Number of addresses in DHCP pool.",
+        &["hub"]
+    )
+    .unwrap();
+    static ref DHCP_LEASE_LIMIT: GaugeVec = register_gauge_vec!(
+        "softether_dhcp_lease_limit_seconds",
+        "DHCP lease limit in seconds.",
+        &["hub"]
+    )
+    .unwrap();
+    static ref DHCP_LEASES: GaugeVec =
+        register_gauge_vec!("softether_dhcp_leases", "Number of DHCP leases.", &["hub"]).unwrap();
+    static ref DHCP_LEASE_EXPIRES_TIMESTAMP: GaugeVec = register_gauge_vec!(
+        "softether_dhcp_lease_expires_timestamp_seconds",
+        "DHCP lease expiration time in seconds since epoch.",
+        &["hub", "mac", "ip", "hostname"]
+    )
+    .unwrap();
+    static ref NAT_ENABLED: GaugeVec =
+        register_gauge_vec!("softether_nat_enabled", "Virtual NAT enabled.", &["hub"]).unwrap();
+    static ref NAT_MTU: GaugeVec =
+        register_gauge_vec!("softether_nat_mtu", "Virtual NAT MTU.", &["hub"]).unwrap();
+    static ref NAT_TCP_TIMEOUT: GaugeVec = register_gauge_vec!(
+        "softether_nat_tcp_timeout_seconds",
+        "Virtual NAT TCP session timeout in seconds.",
+        &["hub"]
+    )
+    .unwrap();
+    static ref NAT_UDP_TIMEOUT: GaugeVec = register_gauge_vec!(
+        "softether_nat_udp_timeout_seconds",
+        "Virtual NAT UDP session timeout in seconds.",
+        &["hub"]
+    )
+    .unwrap();
+    static ref NAT_SESSIONS: GaugeVec = register_gauge_vec!(
+        "softether_nat_sessions",
+        "Number of NAT sessions.",
+        &["hub", "protocol"]
+    )
+    .unwrap();
+
+    static ref ACCESS_LIST_INFO: GaugeVec = register_gauge_vec!(
+        "softether_access_list_info",
+        "A metric with a constant '1' value labeled by access list rule.",
+        &["hub", "id", "action", "priority", "enabled"]
+    )
+    .unwrap();
+
+    static ref GROUP_USERS: GaugeVec = register_gauge_vec!(
+        "softether_group_users",
+        "Number of users in group.",
+        &["hub", "group"]
+    )
+    .unwrap();
+    static ref GROUP_TRANSFER_BYTES: GaugeVec = register_gauge_vec!(
+        "softether_group_transfer_bytes",
+        "Group total transfer in bytes.",
+        &["hub", "group"]
+    )
+    .unwrap();
+
+    static ref RADIUS_ENABLED: GaugeVec = register_gauge_vec!(
+        "softether_radius_enabled",
+        "RADIUS server is configured.",
+        &["hub"]
+    )
+    .unwrap();
+    static ref RADIUS_INFO: GaugeVec = register_gauge_vec!(
+        "softether_radius_info",
+        "A metric with a constant '1' value labeled by RADIUS server.",
+        &["hub", "host", "port"]
+    )
+    .unwrap();
+
+    static ref HUB_LIST_UP: GaugeVec = register_gauge_vec!(
+        "softether_hub_list_up",
+        "The last hub list query is successful.",
+        &[]
+    )
+    .unwrap();
+    static ref HUB_TYPE: GaugeVec = register_gauge_vec!(
+        "softether_hub_type",
+        "A metric with a constant '1' value labeled by hub type.",
+        &["hub", "type"]
+    )
+    .unwrap();
+    static ref HUB_LAST_LOGIN_TIMESTAMP: GaugeVec = register_gauge_vec!(
+        "softether_hub_last_login_timestamp_seconds",
+        "Last login time in seconds since epoch.",
+        &["hub"]
+    )
+    .unwrap();
+    static ref HUB_LAST_COMMUNICATION_TIMESTAMP: GaugeVec = register_gauge_vec!(
+        "softether_hub_last_communication_timestamp_seconds",
+        "Last communication time in seconds since epoch.",
+        &["hub"]
+    )
+    .unwrap();
+    static ref HUB_CREATED_TIMESTAMP: GaugeVec = register_gauge_vec!(
+        "softether_hub_created_timestamp_seconds",
+        "Creation time in seconds since epoch.",
+        &["hub"]
+    )
+    .unwrap();
+
+    static ref BRIDGE_UP: GaugeVec = register_gauge_vec!(
+        "softether_bridge_up",
+        "Local bridge is operating.",
+        &["hub", "device"]
+    )
+    .unwrap();
+    static ref BRIDGE_STATUS: GaugeVec = register_gauge_vec!(
+        "softether_bridge_status",
+        "A metric with a constant '1' value labeled by bridge status.",
+        &["hub", "device", "status"]
+    )
+    .unwrap();
+
+    static ref LISTENER_UP: GaugeVec = register_gauge_vec!(
+        "softether_listener_up",
+        "TCP listener is listening.",
+        &["port"]
+    )
+    .unwrap();
+    static ref LISTENER_STATUS: GaugeVec = register_gauge_vec!(
+        "softether_listener_status",
+        "A metric with a constant '1' value labeled by listener status.",
+        &["port", "status"]
+    )
+    .unwrap();
+
+    static ref SERVER_CERT_NOT_BEFORE: GaugeVec = register_gauge_vec!(
+        "softether_server_cert_not_before_timestamp_seconds",
+        "Server certificate validity start time in seconds since epoch.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_CERT_NOT_AFTER: GaugeVec = register_gauge_vec!(
+        "softether_server_cert_not_after_timestamp_seconds",
+        "Server certificate expiry time in seconds since epoch.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_CERT_INFO: GaugeVec = register_gauge_vec!(
+        "softether_server_cert_info",
+        "A metric with a constant '1' value labeled by server certificate subject and issuer.",
+        &["subject", "issuer"]
+    )
+    .unwrap();
+
+    static ref PROTOCOL_ENABLED: GaugeVec = register_gauge_vec!(
+        "softether_protocol_enabled",
+        "Remote access protocol is enabled.",
+        &["protocol"]
+    )
+    .unwrap();
+    static ref PROTOCOL_PORT_INFO: GaugeVec = register_gauge_vec!(
+        "softether_protocol_port_info",
+        "A metric with a constant '1' value labeled by protocol port.",
+        &["protocol", "port"]
+    )
+    .unwrap();
+    static ref IPSEC_DEFAULT_PSK: GaugeVec = register_gauge_vec!(
+        "softether_ipsec_default_psk",
+        "IPsec pre-shared key is the factory default.",
+        &[]
+    )
+    .unwrap();
+    static ref ETHERIP_CLIENTS: GaugeVec = register_gauge_vec!(
+        "softether_etherip_clients",
+        "The number of EtherIP / L2TPv3 clients.",
+        &[]
+    )
+    .unwrap();
+    static ref ETHERIP_CLIENT_INFO: GaugeVec = register_gauge_vec!(
+        "softether_etherip_client_info",
+        "A metric with a constant '1' value labeled by EtherIP / L2TPv3 client.",
+        &["id", "hub", "user"]
+    )
+    .unwrap();
+
+    static ref DDNS_UP: GaugeVec = register_gauge_vec!(
+        "softether_ddns_up",
+        "Dynamic DNS hostname is registered with a global address.",
+        &[]
+    )
+    .unwrap();
+    static ref DDNS_INFO: GaugeVec = register_gauge_vec!(
+        "softether_ddns_info",
+        "A metric with a constant '1' value labeled by dynamic DNS hostname and address.",
+        &["hostname", "ipv4", "ipv6"]
+    )
+    .unwrap();
+    static ref VPN_AZURE_ENABLED: GaugeVec =
+        register_gauge_vec!("softether_vpn_azure_enabled", "VPN Azure is enabled.", &[]).unwrap();
+    static ref VPN_AZURE_CONNECTED: GaugeVec = register_gauge_vec!(
+        "softether_vpn_azure_connected",
+        "VPN Azure cloud server is connected.",
+        &[]
+    )
+    .unwrap();
+
+    static ref CLUSTER_MEMBER_CONTROLLER: GaugeVec = register_gauge_vec!(
+        "softether_cluster_member_controller",
+        "Cluster member is the controller.",
+        &["member"]
+    )
+    .unwrap();
+    static ref CLUSTER_MEMBER_CONNECTED_TIMESTAMP: GaugeVec = register_gauge_vec!(
+        "softether_cluster_member_connected_timestamp_seconds",
+        "Cluster member connection start time in seconds since epoch.",
+        &["member"]
+    )
+    .unwrap();
+    static ref CLUSTER_MEMBER_POINTS: GaugeVec = register_gauge_vec!(
+        "softether_cluster_member_points",
+        "Cluster member load balancing points.",
+        &["member"]
+    )
+    .unwrap();
+    static ref CLUSTER_MEMBER_WEIGHT: GaugeVec = register_gauge_vec!(
+        "softether_cluster_member_weight",
+        "Cluster member performance standard ratio.",
+        &["member"]
+    )
+    .unwrap();
+    static ref CLUSTER_MEMBER_SESSIONS: GaugeVec = register_gauge_vec!(
+        "softether_cluster_member_sessions",
+        "The number of sessions on cluster member.",
+        &["member"]
+    )
+    .unwrap();
+    static ref CLUSTER_MEMBER_TCP_CONNECTIONS: GaugeVec = register_gauge_vec!(
+        "softether_cluster_member_tcp_connections",
+        "The number of TCP connections on cluster member.",
+        &["member"]
+    )
+    .unwrap();
+    static ref CLUSTER_MEMBER_HUBS: GaugeVec = register_gauge_vec!(
+        "softether_cluster_member_hubs",
+        "The number of operating hubs on cluster member.",
+        &["member"]
+    )
+    .unwrap();
+    static ref CLUSTER_CONTROLLER_UP: GaugeVec = register_gauge_vec!(
+        "softether_cluster_controller_up",
+        "Connection to cluster controller is online.",
+        &["controller"]
+    )
+    .unwrap();
+
+    static ref L3_SWITCH_UP: GaugeVec = register_gauge_vec!(
+        "softether_l3_switch_up",
+        "Virtual layer 3 switch is operating.",
+        &["switch"]
+    )
+    .unwrap();
+    static ref L3_SWITCH_STATUS: GaugeVec = register_gauge_vec!(
+        "softether_l3_switch_status",
+        "A metric with a constant '1' value labeled by virtual layer 3 switch status.",
+        &["switch", "status"]
+    )
+    .unwrap();
+    static ref L3_SWITCH_INTERFACES: GaugeVec = register_gauge_vec!(
+        "softether_l3_switch_interfaces",
+        "Number of virtual layer 3 switch interfaces.",
+        &["switch"]
+    )
+    .unwrap();
+    static ref L3_SWITCH_ROUTES: GaugeVec = register_gauge_vec!(
+        "softether_l3_switch_routes",
+        "Number of virtual layer 3 switch routing table entries.",
+        &["switch"]
+    )
+    .unwrap();
+
+    static ref CONNECTIONS: GaugeVec = register_gauge_vec!(
+        "softether_connections",
+        "Number of TCP connections by connection type.",
+        &["type"]
+    )
+    .unwrap();
+    static ref CONNECTION_AGE: HistogramVec = register_histogram_vec!(
+        "softether_connection_age_seconds",
+        "TCP connection age in seconds.",
+        &[],
+        CONNECTION_AGE_BUCKETS.to_vec()
+    )
+    .unwrap();
+
+    static ref LICENSE_INFO: GaugeVec = register_gauge_vec!(
+        "softether_license_info",
+        "A metric with a constant '1' value labeled by license edition.",
+        &["edition"]
+    )
+    .unwrap();
+    static ref LICENSE_EXPIRES_TIMESTAMP: GaugeVec = register_gauge_vec!(
+        "softether_license_expires_timestamp_seconds",
+        "License expiration time in seconds since epoch.",
+        &[]
+    )
+    .unwrap();
+    static ref MAX_SESSIONS: GaugeVec = register_gauge_vec!(
+        "softether_server_max_sessions",
+        "Maximum number of sessions.",
+        &[]
+    )
+    .unwrap();
+    static ref MAX_USERS: GaugeVec = register_gauge_vec!(
+        "softether_server_max_users_per_hub",
+        "Maximum number of users per hub.",
+        &[]
+    )
+    .unwrap();
+    static ref CAPABILITY: GaugeVec = register_gauge_vec!(
+        "softether_capability",
+        "Server capability is supported.",
+        &["name"]
+    )
+    .unwrap();
+
+    static ref SERVER_CIPHER_INFO: GaugeVec = register_gauge_vec!(
+        "softether_server_cipher_info",
+        "A metric with a constant '1' value labeled by server cipher.",
+        &["cipher"]
+    )
+    .unwrap();
+    static ref KEEP_ALIVE_ENABLED: GaugeVec = register_gauge_vec!(
+        "softether_keep_alive_enabled",
+        "Keep alive internet connection is enabled.",
+        &[]
+    )
+    .unwrap();
+    static ref KEEP_ALIVE_INTERVAL: GaugeVec = register_gauge_vec!(
+        "softether_keep_alive_interval_seconds",
+        "Keep alive packet send interval in seconds.",
+        &[]
+    )
+    .unwrap();
+    static ref KEEP_ALIVE_INFO: GaugeVec = register_gauge_vec!(
+        "softether_keep_alive_info",
+        "A metric with a constant '1' value labeled by keep alive destination.",
+        &["host", "port", "protocol"]
+    )
+    .unwrap();
+
+    static ref SERVER_UP: GaugeVec = register_gauge_vec!(
+        "softether_server_up",
+        "The last server query is successful.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_INFO: GaugeVec = register_gauge_vec!(
+        "softether_server_info",
+        "A metric with a constant '1' value labeled by server product, version, build, OS and server type.",
+        &["product", "version", "build", "os", "server_type"]
+    )
+    .unwrap();
+    static ref SERVER_START_TIME: GaugeVec = register_gauge_vec!(
+        "softether_server_start_time_seconds",
+        "Server start time in seconds since epoch.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_HUBS: GaugeVec =
+        register_gauge_vec!("softether_server_hubs", "Number of hubs.", &[]).unwrap();
+    static ref SERVER_HUBS_STATIC: GaugeVec = register_gauge_vec!(
+        "softether_server_hubs_static",
+        "Number of static hubs.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_HUBS_DYNAMIC: GaugeVec = register_gauge_vec!(
+        "softether_server_hubs_dynamic",
+        "Number of dynamic hubs.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_SESSIONS: GaugeVec =
+        register_gauge_vec!("softether_server_sessions", "Number of sessions.", &[]).unwrap();
+    static ref SERVER_TCP_CONNECTIONS: GaugeVec = register_gauge_vec!(
+        "softether_server_tcp_connections",
+        "Number of TCP connections.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_MAC_TABLES: GaugeVec = register_gauge_vec!(
+        "softether_server_mac_tables",
+        "Number of entries in MAC table.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_IP_TABLES: GaugeVec = register_gauge_vec!(
+        "softether_server_ip_tables",
+        "Number of entries in IP table.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_USERS: GaugeVec =
+        register_gauge_vec!("softether_server_users", "Number of users.", &[]).unwrap();
+    static ref SERVER_GROUPS: GaugeVec =
+        register_gauge_vec!("softether_server_groups", "Number of groups.", &[]).unwrap();
+    static ref SERVER_MEMORY_TOTAL_BYTES: GaugeVec = register_gauge_vec!(
+        "softether_server_memory_total_bytes",
+        "Total memory in bytes.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_MEMORY_USED_BYTES: GaugeVec = register_gauge_vec!(
+        "softether_server_memory_used_bytes",
+        "Used memory in bytes.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_MEMORY_FREE_BYTES: GaugeVec = register_gauge_vec!(
+        "softether_server_memory_free_bytes",
+        "Free memory in bytes.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_PHYSICAL_MEMORY_TOTAL_BYTES: GaugeVec = register_gauge_vec!(
+        "softether_server_physical_memory_total_bytes",
+        "Total physical memory in bytes.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_PHYSICAL_MEMORY_USED_BYTES: GaugeVec = register_gauge_vec!(
+        "softether_server_physical_memory_used_bytes",
+        "Used physical memory in bytes.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_PHYSICAL_MEMORY_FREE_BYTES: GaugeVec = register_gauge_vec!(
+        "softether_server_physical_memory_free_bytes",
+        "Free physical memory in bytes.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_OUTGOING_UNICAST_PACKETS: GaugeVec = register_gauge_vec!(
+        "softether_server_outgoing_unicast_packets",
+        "Outgoing unicast transfer in packets.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_OUTGOING_UNICAST_BYTES: GaugeVec = register_gauge_vec!(
+        "softether_server_outgoing_unicast_bytes",
+        "Outgoing unicast transfer in bytes.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_OUTGOING_BROADCAST_PACKETS: GaugeVec = register_gauge_vec!(
+        "softether_server_outgoing_broadcast_packets",
+        "Outgoing broadcast transfer in packets.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_OUTGOING_BROADCAST_BYTES: GaugeVec = register_gauge_vec!(
+        "softether_server_outgoing_broadcast_bytes",
+        "Outgoing broadcast transfer in bytes.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_INCOMING_UNICAST_PACKETS: GaugeVec = register_gauge_vec!(
+        "softether_server_incoming_unicast_packets",
+        "Incoming unicast transfer in packets.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_INCOMING_UNICAST_BYTES: GaugeVec = register_gauge_vec!(
+        "softether_server_incoming_unicast_bytes",
+        "Incoming unicast transfer in bytes.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_INCOMING_BROADCAST_PACKETS: GaugeVec = register_gauge_vec!(
+        "softether_server_incoming_broadcast_packets",
+        "Incoming broadcast transfer in packets.",
+        &[]
+    )
+    .unwrap();
+    static ref SERVER_INCOMING_BROADCAST_BYTES: GaugeVec = register_gauge_vec!(
+        "softether_server_incoming_broadcast_bytes",
+        "Incoming broadcast transfer in bytes.",
+        &[]
     )
     .unwrap();
 }
@@ -135,13 +865,46 @@ static RUST_VERSION: Option<&'static str> = option_env!("RUST_VERSION");
 pub struct Config {
     vpncmd: Option<String>,
     server: Option<String>,
+    server_password: Option<String>,
+    #[serde(default)]
     hubs: Vec<Hub>,
+    collectors: Option<Collectors>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct Hub {
     name: Option<String>,
     password: Option<String>,
+    exclude: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct Collectors {
+    users: Option<bool>,
+    sessions: Option<bool>,
+    session_details: Option<bool>,
+    session_details_limit: Option<usize>,
+    mac_tables: Option<bool>,
+    ip_tables: Option<bool>,
+    cascades: Option<bool>,
+    secure_nat: Option<bool>,
+    bridges: Option<bool>,
+    listeners: Option<bool>,
+    server_cert: Option<bool>,
+    protocols: Option<bool>,
+    dynamic_dns: Option<bool>,
+    vpn_azure: Option<bool>,
+    cluster: Option<bool>,
+    access_lists: Option<bool>,
+    groups: Option<bool>,
+    l3_switches: Option<bool>,
+    connections: Option<bool>,
+    license: Option<bool>,
+    capabilities: Option<bool>,
+    server_cipher: Option<bool>,
+    keep_alive: Option<bool>,
+    radius: Option<bool>,
+    server_info: Option<bool>,
 }
 
 impl Config {
@@ -154,6 +917,9 @@ impl Config {
     }
 }
 
+// Buckets of softether_connection_age_seconds
+const CONNECTION_AGE_BUCKETS: [f64; 8] = [1.0, 10.0, 60.0, 300.0, 1800.0, 3600.0, 21600.0, 86400.0];
+
 pub struct Exporter;
 
 impl Exporter {
@@ -161,7 +927,9 @@ impl Exporter {
         let encoder = TextEncoder::new();
         let vpncmd = config.vpncmd.unwrap_or(String::from("vpncmd"));
         let server = config.server.unwrap_or(String::from("localhost"));
+        let server_password = config.server_password;
         let hubs = config.hubs;
+        let collectors = config.collectors.unwrap_or_default();
 
         let addr = if listen_address.starts_with(":") {
             format!("0.0.0.0{}", listen_address)
@@ -173,9 +941,218 @@ impl Exporter {
 
         Server::http(addr)?.handle(move |req: Request, mut res: Response| {
             if req.uri == RequestUri::AbsolutePath("/metrics".to_string()) {
-                for hub in hubs.clone() {
-                    let name = hub.name.unwrap_or(String::from(""));
-                    let password = hub.password.unwrap_or(String::from(""));
+                // Remove series of entries and hubs which no longer exist
+                HUB_LIST_UP.reset();
+                HUB_TYPE.reset();
+                UP.reset();
+                ONLINE.reset();
+                SESSIONS.reset();
+                SESSIONS_CLIENT.reset();
+                SESSIONS_BRIDGE.reset();
+                SECURE_NAT.reset();
+                ACCESS_LISTS.reset();
+                USERS.reset();
+                GROUPS.reset();
+                MAC_TABLES.reset();
+                IP_TABLES.reset();
+                LOGINS.reset();
+                TCP_CONNECTIONS.reset();
+                MAX_TCP_CONNECTIONS.reset();
+                OUTGOING_UNICAST_PACKETS.reset();
+                OUTGOING_UNICAST_BYTES.reset();
+                OUTGOING_BROADCAST_PACKETS.reset();
+                OUTGOING_BROADCAST_BYTES.reset();
+                INCOMING_UNICAST_PACKETS.reset();
+                INCOMING_UNICAST_BYTES.reset();
+                INCOMING_BROADCAST_PACKETS.reset();
+                INCOMING_BROADCAST_BYTES.reset();
+                USER_TRANSFER_BYTES.reset();
+                USER_TRANSFER_PACKETS.reset();
+                USER_TCP_CONNECTIONS.reset();
+                USER_MAX_TCP_CONNECTIONS.reset();
+                HUB_LAST_LOGIN_TIMESTAMP.reset();
+                HUB_LAST_COMMUNICATION_TIMESTAMP.reset();
+                HUB_CREATED_TIMESTAMP.reset();
+                USER_INFO.reset();
+                USER_LOGINS.reset();
+                USER_LAST_LOGIN_TIMESTAMP.reset();
+                USER_EXPIRES_TIMESTAMP.reset();
+                USER_TOTAL_TRANSFER_BYTES.reset();
+                USER_TOTAL_TRANSFER_PACKETS.reset();
+                SESSION_TRANSFER_BYTES.reset();
+                SESSION_TRANSFER_PACKETS.reset();
+                SESSION_TCP_CONNECTIONS.reset();
+                SESSION_MAX_TCP_CONNECTIONS.reset();
+                SESSION_INFO.reset();
+                SESSION_START_TIME.reset();
+                MAC_TABLE_ENTRY.reset();
+                MAC_TABLE_CREATED_TIMESTAMP.reset();
+                MAC_TABLE_UPDATED_TIMESTAMP.reset();
+                IP_TABLE_ENTRY.reset();
+                IP_TABLE_CREATED_TIMESTAMP.reset();
+                IP_TABLE_UPDATED_TIMESTAMP.reset();
+                DHCP_ENABLED.reset();
+                DHCP_POOL_SIZE.reset();
+                DHCP_LEASE_LIMIT.reset();
+                DHCP_LEASES.reset();
+                DHCP_LEASE_EXPIRES_TIMESTAMP.reset();
+                NAT_ENABLED.reset();
+                NAT_MTU.reset();
+                NAT_TCP_TIMEOUT.reset();
+                NAT_UDP_TIMEOUT.reset();
+                NAT_SESSIONS.reset();
+                CASCADE_INFO.reset();
+                CASCADE_UP.reset();
+                CASCADE_START_TIME.reset();
+                CASCADE_FIRST_ESTABLISHED_TIMESTAMP.reset();
+                CASCADE_ESTABLISHED_TIMESTAMP.reset();
+                CASCADE_ESTABLISHED_COUNT.reset();
+                CASCADE_TCP_CONNECTIONS.reset();
+                CASCADE_MAX_TCP_CONNECTIONS.reset();
+                CASCADE_OUTGOING_BYTES.reset();
+                CASCADE_INCOMING_BYTES.reset();
+                CASCADE_OUTGOING_PACKETS.reset();
+                CASCADE_INCOMING_PACKETS.reset();
+                BRIDGE_UP.reset();
+                BRIDGE_STATUS.reset();
+                LISTENER_UP.reset();
+                LISTENER_STATUS.reset();
+                SERVER_CERT_INFO.reset();
+                SERVER_CERT_NOT_BEFORE.reset();
+                SERVER_CERT_NOT_AFTER.reset();
+                PROTOCOL_ENABLED.reset();
+                PROTOCOL_PORT_INFO.reset();
+                ETHERIP_CLIENT_INFO.reset();
+                IPSEC_DEFAULT_PSK.reset();
+                ETHERIP_CLIENTS.reset();
+                DDNS_INFO.reset();
+                DDNS_UP.reset();
+                VPN_AZURE_ENABLED.reset();
+                VPN_AZURE_CONNECTED.reset();
+                CLUSTER_MEMBER_CONTROLLER.reset();
+                CLUSTER_MEMBER_CONNECTED_TIMESTAMP.reset();
+                CLUSTER_MEMBER_POINTS.reset();
+                CLUSTER_MEMBER_WEIGHT.reset();
+                CLUSTER_MEMBER_SESSIONS.reset();
+                CLUSTER_MEMBER_TCP_CONNECTIONS.reset();
+                CLUSTER_MEMBER_HUBS.reset();
+                CLUSTER_CONTROLLER_UP.reset();
+                ACCESS_LIST_INFO.reset();
+                GROUP_USERS.reset();
+                GROUP_TRANSFER_BYTES.reset();
+                L3_SWITCH_UP.reset();
+                L3_SWITCH_STATUS.reset();
+                L3_SWITCH_INTERFACES.reset();
+                L3_SWITCH_ROUTES.reset();
+                CONNECTIONS.reset();
+                CONNECTION_AGE.reset();
+                SERVER_INFO.reset();
+                LICENSE_INFO.reset();
+                LICENSE_EXPIRES_TIMESTAMP.reset();
+                MAX_SESSIONS.reset();
+                MAX_USERS.reset();
+                CAPABILITY.reset();
+                SERVER_CIPHER_INFO.reset();
+                KEEP_ALIVE_INFO.reset();
+                KEEP_ALIVE_ENABLED.reset();
+                KEEP_ALIVE_INTERVAL.reset();
+                RADIUS_ENABLED.reset();
+                RADIUS_INFO.reset();
+                SERVER_UP.reset();
+                SERVER_START_TIME.reset();
+                SERVER_HUBS.reset();
+                SERVER_HUBS_STATIC.reset();
+                SERVER_HUBS_DYNAMIC.reset();
+                SERVER_SESSIONS.reset();
+                SERVER_TCP_CONNECTIONS.reset();
+                SERVER_MAC_TABLES.reset();
+                SERVER_IP_TABLES.reset();
+                SERVER_USERS.reset();
+                SERVER_GROUPS.reset();
+                SERVER_MEMORY_TOTAL_BYTES.reset();
+                SERVER_MEMORY_USED_BYTES.reset();
+                SERVER_MEMORY_FREE_BYTES.reset();
+                SERVER_PHYSICAL_MEMORY_TOTAL_BYTES.reset();
+                SERVER_PHYSICAL_MEMORY_USED_BYTES.reset();
+                SERVER_PHYSICAL_MEMORY_FREE_BYTES.reset();
+                SERVER_OUTGOING_UNICAST_PACKETS.reset();
+                SERVER_OUTGOING_UNICAST_BYTES.reset();
+                SERVER_OUTGOING_BROADCAST_PACKETS.reset();
+                SERVER_OUTGOING_BROADCAST_BYTES.reset();
+                SERVER_INCOMING_UNICAST_PACKETS.reset();
+                SERVER_INCOMING_UNICAST_BYTES.reset();
+                SERVER_INCOMING_BROADCAST_PACKETS.reset();
+                SERVER_INCOMING_BROADCAST_BYTES.reset();
+
+                if let Some(ref password) = server_password {
+                    Exporter::collect_server(&vpncmd, &server, password);
+
+                    if collectors.server_info == Some(true) {
+                        Exporter::collect_server_info(&vpncmd, &server, password);
+                    }
+
+                    if collectors.bridges == Some(true) {
+                        Exporter::collect_bridges(&vpncmd, &server, password);
+                    }
+
+                    if collectors.listeners == Some(true) {
+                        Exporter::collect_listeners(&vpncmd, &server, password);
+                    }
+
+                    if collectors.server_cert == Some(true) {
+                        Exporter::collect_server_cert(&vpncmd, &server, password);
+                    }
+
+                    if collectors.protocols == Some(true) {
+                        Exporter::collect_protocols(&vpncmd, &server, password);
+                    }
+
+                    if collectors.dynamic_dns == Some(true) {
+                        Exporter::collect_dynamic_dns(&vpncmd, &server, password);
+                    }
+
+                    if collectors.vpn_azure == Some(true) {
+                        Exporter::collect_vpn_azure(&vpncmd, &server, password);
+                    }
+
+                    if collectors.cluster == Some(true) {
+                        Exporter::collect_cluster(&vpncmd, &server, password);
+                    }
+
+                    if collectors.l3_switches == Some(true) {
+                        Exporter::collect_l3_switches(&vpncmd, &server, password);
+                    }
+
+                    if collectors.connections == Some(true) {
+                        Exporter::collect_connections(&vpncmd, &server, password);
+                    }
+
+                    if collectors.license == Some(true) {
+                        Exporter::collect_license(&vpncmd, &server, password);
+                    }
+
+                    if collectors.capabilities == Some(true) {
+                        Exporter::collect_caps(&vpncmd, &server, password);
+                    }
+
+                    if collectors.server_cipher == Some(true) {
+                        Exporter::collect_server_cipher(&vpncmd, &server, password);
+                    }
+
+                    if collectors.keep_alive == Some(true) {
+                        Exporter::collect_keep_alive(&vpncmd, &server, password);
+                    }
+                }
+
+                let discovered = match server_password {
+                    Some(ref password) => {
+                        Exporter::collect_hub_list(&vpncmd, &server, password, &hubs)
+                    }
+                    None => Vec::new(),
+                };
+
+                let targets = Exporter::hub_targets(&discovered, &server_password, &hubs);
+                for (name, password) in targets {
                     let status =
                         match SoftEtherReader::hub_status(&vpncmd, &server, &name, &password) {
                             Ok(x) => x,
@@ -209,6 +1186,12 @@ impl Exporter {
                     SESSIONS_BRIDGE
                         .with_label_values(&[&status.name])
                         .set(status.sessions_bridge);
+                    SECURE_NAT
+                        .with_label_values(&[&status.name])
+                        .set(if status.secure_nat { 1.0 } else { 0.0 });
+                    ACCESS_LISTS
+                        .with_label_values(&[&status.name])
+                        .set(status.access_lists);
                     USERS.with_label_values(&[&status.name]).set(status.users);
                     GROUPS.with_label_values(&[&status.name]).set(status.groups);
                     MAC_TABLES
@@ -218,6 +1201,15 @@ impl Exporter {
                         .with_label_values(&[&status.name])
                         .set(status.ip_tables);
                     LOGINS.with_label_values(&[&status.name]).set(status.logins);
+                    HUB_LAST_LOGIN_TIMESTAMP
+                        .with_label_values(&[&status.name])
+                        .set(status.last_login);
+                    HUB_LAST_COMMUNICATION_TIMESTAMP
+                        .with_label_values(&[&status.name])
+                        .set(status.last_communication);
+                    HUB_CREATED_TIMESTAMP
+                        .with_label_values(&[&status.name])
+                        .set(status.created);
                     OUTGOING_UNICAST_PACKETS
                         .with_label_values(&[&status.name])
                         .set(status.outgoing_unicast_packets);
@@ -243,9 +1235,58 @@ impl Exporter {
                         .with_label_values(&[&status.name])
                         .set(status.incoming_broadcast_bytes);
 
+                    if collectors.sessions == Some(true) {
+                        Exporter::collect_sessions(&status.name, &sessions);
+                    }
+
+                    if collectors.mac_tables == Some(true) {
+                        Exporter::collect_mac_tables(&vpncmd, &server, &name, &password, &sessions);
+                    }
+
+                    // IpTable is shared by ip_tables and session_details collectors
+                    let ip_tables = if collectors.ip_tables == Some(true)
+                        || collectors.session_details == Some(true)
+                    {
+                        match SoftEtherReader::hub_ip_tables(&vpncmd, &server, &name, &password) {
+                            Ok(x) => Some(x),
+                            Err(x) => {
+                                println!("Hub IP table read failed: {}", x);
+                                None
+                            }
+                        }
+                    } else {
+                        None
+                    };
+
+                    if collectors.ip_tables == Some(true) {
+                        if let Some(ref ip_tables) = ip_tables {
+                            Exporter::collect_ip_tables(&name, &sessions, ip_tables);
+                        }
+                    }
+
+                    if collectors.session_details == Some(true) {
+                        let limit = collectors.session_details_limit.unwrap_or(100);
+                        Exporter::collect_session_details(
+                            &vpncmd,
+                            &server,
+                            &name,
+                            &password,
+                            &sessions,
+                            ip_tables.as_deref().unwrap_or(&[]),
+                            limit,
+                        );
+                    }
+
                     let mut transfer_bytes = HashMap::new();
                     let mut transfer_packets = HashMap::new();
+                    let mut tcp_connections = HashMap::new();
+                    let mut max_tcp_connections = HashMap::new();
                     for session in sessions {
+                        *tcp_connections.entry(session.user.clone()).or_insert(0.0) +=
+                            session.connections.0;
+                        *max_tcp_connections
+                            .entry(session.user.clone())
+                            .or_insert(0.0) += session.connections.1;
                         if let Some(val) = transfer_bytes.get(&session.user) {
                             let val = val + session.transfer_bytes;
                             transfer_bytes.insert(session.user.clone(), val);
@@ -271,6 +1312,68 @@ impl Exporter {
                             .with_label_values(&[&status.name, user])
                             .set(*packets);
                     }
+                    for (user, connections) in &tcp_connections {
+                        USER_TCP_CONNECTIONS
+                            .with_label_values(&[&status.name, user])
+                            .set(*connections);
+                    }
+                    for (user, connections) in &max_tcp_connections {
+                        USER_MAX_TCP_CONNECTIONS
+                            .with_label_values(&[&status.name, user])
+                            .set(*connections);
+                    }
+                    TCP_CONNECTIONS
+                        .with_label_values(&[&status.name])
+                        .set(tcp_connections.values().sum());
+                    MAX_TCP_CONNECTIONS
+                        .with_label_values(&[&status.name])
+                        .set(max_tcp_connections.values().sum());
+
+                    // UserList is shared by users and groups collectors
+                    let users = if collectors.users == Some(true) || collectors.groups == Some(true)
+                    {
+                        match SoftEtherReader::hub_users(&vpncmd, &server, &name, &password) {
+                            Ok(x) => Some(x),
+                            Err(x) => {
+                                println!("Hub users read failed: {}", x);
+                                None
+                            }
+                        }
+                    } else {
+                        None
+                    };
+
+                    if collectors.users == Some(true) {
+                        if let Some(ref users) = users {
+                            Exporter::collect_users(&name, users);
+                        }
+                    }
+
+                    if collectors.cascades == Some(true) {
+                        Exporter::collect_cascades(&vpncmd, &server, &name, &password);
+                    }
+
+                    if collectors.secure_nat == Some(true) && status.secure_nat {
+                        Exporter::collect_secure_nat(&vpncmd, &server, &name, &password);
+                    }
+
+                    if collectors.access_lists == Some(true) {
+                        Exporter::collect_access_lists(&vpncmd, &server, &name, &password);
+                    }
+
+                    if collectors.groups == Some(true) {
+                        Exporter::collect_groups(
+                            &vpncmd,
+                            &server,
+                            &name,
+                            &password,
+                            users.as_deref(),
+                        );
+                    }
+
+                    if collectors.radius == Some(true) {
+                        Exporter::collect_radius(&vpncmd, &server, &name, &password);
+                    }
                 }
 
                 let git_revision = GIT_REVISION.unwrap_or("");
@@ -294,4 +1397,890 @@ impl Exporter {
 
         Ok(())
     }
+
+    fn collect_hub_list(vpncmd: &str, server: &str, password: &str, hubs: &[Hub]) -> Vec<String> {
+        let list = match SoftEtherReader::hub_list(vpncmd, server, password) {
+            Ok(x) => x,
+            Err(x) => {
+                HUB_LIST_UP.with_label_values(&[]).set(0.0);
+                println!("Hub list read failed: {}", x);
+                return Vec::new();
+            }
+        };
+
+        HUB_LIST_UP.with_label_values(&[]).set(1.0);
+
+        let mut discovered = Vec::new();
+        for item in list {
+            let excluded = hubs
+                .iter()
+                .any(|x| x.name.as_deref() == Some(item.name.as_str()) && x.exclude == Some(true));
+            if excluded {
+                continue;
+            }
+
+            HUB_TYPE
+                .with_label_values(&[&item.name, &item.hub_type])
+                .set(1.0);
+
+            discovered.push(item.name);
+        }
+
+        discovered
+    }
+
+    fn hub_targets(
+        discovered: &[String],
+        server_password: &Option<String>,
+        hubs: &[Hub],
+    ) -> Vec<(String, String)> {
+        let mut targets = Vec::new();
+
+        for name in discovered {
+            // Explicitly listed hubs override the password
+            let password = hubs
+                .iter()
+                .find(|x| x.name.as_ref() == Some(name))
+                .and_then(|x| x.password.clone())
+                .or(server_password.clone())
+                .unwrap_or(String::from(""));
+            targets.push((name.clone(), password));
+        }
+
+        for hub in hubs {
+            if hub.exclude == Some(true) {
+                continue;
+            }
+            let name = hub.name.clone().unwrap_or(String::from(""));
+            if discovered.contains(&name) {
+                continue;
+            }
+            let password = hub
+                .password
+                .clone()
+                .or(server_password.clone())
+                .unwrap_or(String::from(""));
+            targets.push((name, password));
+        }
+
+        targets
+    }
+
+    fn collect_sessions(hub: &str, sessions: &[HubSession]) {
+        for session in sessions {
+            let labels = [
+                hub,
+                &session.name,
+                &session.user,
+                &session.source,
+                &session.location,
+            ];
+            SESSION_TRANSFER_BYTES
+                .with_label_values(&labels)
+                .set(session.transfer_bytes);
+            SESSION_TRANSFER_PACKETS
+                .with_label_values(&labels)
+                .set(session.transfer_packets);
+            SESSION_TCP_CONNECTIONS
+                .with_label_values(&labels)
+                .set(session.connections.0);
+            SESSION_MAX_TCP_CONNECTIONS
+                .with_label_values(&labels)
+                .set(session.connections.1);
+        }
+    }
+
+    fn collect_session_details(
+        vpncmd: &str,
+        server: &str,
+        hub: &str,
+        password: &str,
+        sessions: &[HubSession],
+        ip_tables: &[IpTableEntry],
+        limit: usize,
+    ) {
+        // SessionGet does not show the virtual IP, so it is taken from IpTable
+        let mut virtual_ips: HashMap<&str, Vec<&str>> = HashMap::new();
+        for entry in ip_tables {
+            virtual_ips
+                .entry(&entry.session)
+                .or_default()
+                .push(&entry.ip);
+        }
+
+        for session in sessions.iter().take(limit) {
+            let detail = match SoftEtherReader::hub_session_detail(
+                vpncmd,
+                server,
+                hub,
+                password,
+                &session.name,
+            ) {
+                Ok(x) => x,
+                Err(x) => {
+                    println!("Session detail read failed: {}", x);
+                    continue;
+                }
+            };
+
+            let virtual_ip = virtual_ips
+                .get(session.name.as_str())
+                .map(|x| x.join(","))
+                .unwrap_or_default();
+            SESSION_INFO
+                .with_label_values(&[
+                    hub,
+                    &session.name,
+                    &session.user,
+                    &detail.client_ip,
+                    &detail.client_reported_ip,
+                    &virtual_ip,
+                    &detail.client_hostname,
+                    &detail.client_product,
+                    &detail.client_version,
+                    &detail.client_os,
+                    &detail.cipher,
+                    &detail.compression.to_string(),
+                    &detail.udp_acceleration.to_string(),
+                    &detail.half_duplex.to_string(),
+                ])
+                .set(1.0);
+            SESSION_START_TIME
+                .with_label_values(&[hub, &session.name, &session.user])
+                .set(detail.start_time);
+        }
+    }
+
+    fn collect_mac_tables(
+        vpncmd: &str,
+        server: &str,
+        hub: &str,
+        password: &str,
+        sessions: &[HubSession],
+    ) {
+        let entries = match SoftEtherReader::hub_mac_tables(vpncmd, server, hub, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("Hub MAC table read failed: {}", x);
+                return;
+            }
+        };
+
+        for entry in entries {
+            let user = Exporter::session_user(sessions, &entry.session);
+            let labels = [hub, &entry.session, user, &entry.mac, &entry.vlan_id];
+            MAC_TABLE_ENTRY.with_label_values(&labels).set(1.0);
+            MAC_TABLE_CREATED_TIMESTAMP
+                .with_label_values(&labels)
+                .set(entry.created);
+            MAC_TABLE_UPDATED_TIMESTAMP
+                .with_label_values(&labels)
+                .set(entry.updated);
+        }
+    }
+
+    fn collect_ip_tables(hub: &str, sessions: &[HubSession], entries: &[IpTableEntry]) {
+        for entry in entries {
+            let user = Exporter::session_user(sessions, &entry.session);
+            let dhcp = entry.dhcp.to_string();
+            let labels = [hub, &entry.session, user, &entry.ip, &dhcp];
+            IP_TABLE_ENTRY.with_label_values(&labels).set(1.0);
+            IP_TABLE_CREATED_TIMESTAMP
+                .with_label_values(&labels)
+                .set(entry.created);
+            IP_TABLE_UPDATED_TIMESTAMP
+                .with_label_values(&labels)
+                .set(entry.updated);
+        }
+    }
+
+    fn session_user<'a>(sessions: &'a [HubSession], session: &str) -> &'a str {
+        sessions
+            .iter()
+            .find(|x| x.name == session)
+            .map(|x| x.user.as_str())
+            .unwrap_or("")
+    }
+
+    fn collect_users(hub: &str, users: &[HubUser]) {
+        for user in users {
+            USER_INFO
+                .with_label_values(&[hub, &user.name, &user.group, &user.auth_type])
+                .set(1.0);
+            USER_LOGINS
+                .with_label_values(&[hub, &user.name])
+                .set(user.logins);
+            USER_LAST_LOGIN_TIMESTAMP
+                .with_label_values(&[hub, &user.name])
+                .set(user.last_login);
+            if let Some(expires) = user.expires {
+                USER_EXPIRES_TIMESTAMP
+                    .with_label_values(&[hub, &user.name])
+                    .set(expires);
+            }
+            USER_TOTAL_TRANSFER_BYTES
+                .with_label_values(&[hub, &user.name])
+                .set(user.transfer_bytes);
+            USER_TOTAL_TRANSFER_PACKETS
+                .with_label_values(&[hub, &user.name])
+                .set(user.transfer_packets);
+        }
+    }
+
+    fn collect_cascades(vpncmd: &str, server: &str, hub: &str, password: &str) {
+        let cascades = match SoftEtherReader::hub_cascades(vpncmd, server, hub, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("Hub cascades read failed: {}", x);
+                return;
+            }
+        };
+
+        for cascade in cascades {
+            let labels = [hub, &cascade.name];
+            CASCADE_INFO
+                .with_label_values(&[hub, &cascade.name, &cascade.server, &cascade.hub])
+                .set(1.0);
+            CASCADE_UP
+                .with_label_values(&labels)
+                .set(if cascade.online { 1.0 } else { 0.0 });
+
+            let status = match SoftEtherReader::hub_cascade_status(
+                vpncmd,
+                server,
+                hub,
+                password,
+                &cascade.name,
+            ) {
+                Ok(x) => x,
+                Err(x) => {
+                    println!("Cascade status read failed: {}", x);
+                    continue;
+                }
+            };
+
+            CASCADE_START_TIME
+                .with_label_values(&labels)
+                .set(status.start_time);
+            CASCADE_FIRST_ESTABLISHED_TIMESTAMP
+                .with_label_values(&labels)
+                .set(status.first_established);
+            CASCADE_ESTABLISHED_TIMESTAMP
+                .with_label_values(&labels)
+                .set(status.established);
+            CASCADE_ESTABLISHED_COUNT
+                .with_label_values(&labels)
+                .set(status.established_count);
+            CASCADE_TCP_CONNECTIONS
+                .with_label_values(&labels)
+                .set(status.tcp_connections);
+            CASCADE_MAX_TCP_CONNECTIONS
+                .with_label_values(&labels)
+                .set(status.max_tcp_connections);
+            CASCADE_OUTGOING_BYTES
+                .with_label_values(&labels)
+                .set(status.outgoing_bytes);
+            CASCADE_INCOMING_BYTES
+                .with_label_values(&labels)
+                .set(status.incoming_bytes);
+            CASCADE_OUTGOING_PACKETS
+                .with_label_values(&labels)
+                .set(status.outgoing_packets);
+            CASCADE_INCOMING_PACKETS
+                .with_label_values(&labels)
+                .set(status.incoming_packets);
+        }
+    }
+
+    fn collect_secure_nat(vpncmd: &str, server: &str, hub: &str, password: &str) {
+        match SoftEtherReader::hub_dhcp(vpncmd, server, hub, password) {
+            Ok(dhcp) => {
+                DHCP_ENABLED
+                    .with_label_values(&[hub])
+                    .set(if dhcp.enabled { 1.0 } else { 0.0 });
+                DHCP_POOL_SIZE.with_label_values(&[hub]).set(dhcp.pool_size);
+                DHCP_LEASE_LIMIT
+                    .with_label_values(&[hub])
+                    .set(dhcp.lease_limit);
+            }
+            Err(x) => {
+                println!("Hub DHCP read failed: {}", x);
+            }
+        }
+
+        match SoftEtherReader::hub_dhcp_leases(vpncmd, server, hub, password) {
+            Ok(leases) => {
+                DHCP_LEASES
+                    .with_label_values(&[hub])
+                    .set(leases.len() as f64);
+                for lease in leases {
+                    DHCP_LEASE_EXPIRES_TIMESTAMP
+                        .with_label_values(&[hub, &lease.mac, &lease.ip, &lease.hostname])
+                        .set(lease.expires);
+                }
+            }
+            Err(x) => {
+                println!("Hub DHCP table read failed: {}", x);
+            }
+        }
+
+        match SoftEtherReader::hub_nat(vpncmd, server, hub, password) {
+            Ok(nat) => {
+                NAT_ENABLED
+                    .with_label_values(&[hub])
+                    .set(if nat.enabled { 1.0 } else { 0.0 });
+                NAT_MTU.with_label_values(&[hub]).set(nat.mtu);
+                NAT_TCP_TIMEOUT
+                    .with_label_values(&[hub])
+                    .set(nat.tcp_timeout);
+                NAT_UDP_TIMEOUT
+                    .with_label_values(&[hub])
+                    .set(nat.udp_timeout);
+            }
+            Err(x) => {
+                println!("Hub NAT read failed: {}", x);
+            }
+        }
+
+        match SoftEtherReader::hub_nat_sessions(vpncmd, server, hub, password) {
+            Ok(sessions) => {
+                let mut protocols = HashMap::new();
+                for session in sessions {
+                    *protocols.entry(session.protocol).or_insert(0.0) += 1.0;
+                }
+                for (protocol, count) in &protocols {
+                    NAT_SESSIONS.with_label_values(&[hub, protocol]).set(*count);
+                }
+            }
+            Err(x) => {
+                println!("Hub NAT table read failed: {}", x);
+            }
+        }
+    }
+
+    fn collect_bridges(vpncmd: &str, server: &str, password: &str) {
+        let bridges = match SoftEtherReader::bridges(vpncmd, server, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("Bridge list read failed: {}", x);
+                return;
+            }
+        };
+
+        for bridge in bridges {
+            BRIDGE_UP
+                .with_label_values(&[&bridge.hub, &bridge.device])
+                .set(if bridge.status == "operating" {
+                    1.0
+                } else {
+                    0.0
+                });
+            BRIDGE_STATUS
+                .with_label_values(&[&bridge.hub, &bridge.device, &bridge.status])
+                .set(1.0);
+        }
+    }
+
+    fn collect_listeners(vpncmd: &str, server: &str, password: &str) {
+        let listeners = match SoftEtherReader::listeners(vpncmd, server, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("Listener list read failed: {}", x);
+                return;
+            }
+        };
+
+        for listener in listeners {
+            LISTENER_UP.with_label_values(&[&listener.port]).set(
+                if listener.status == "listening" {
+                    1.0
+                } else {
+                    0.0
+                },
+            );
+            LISTENER_STATUS
+                .with_label_values(&[&listener.port, &listener.status])
+                .set(1.0);
+        }
+    }
+
+    fn collect_server_cert(vpncmd: &str, server: &str, password: &str) {
+        let cert = match SoftEtherReader::server_cert(vpncmd, server, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("Server certificate read failed: {}", x);
+                return;
+            }
+        };
+
+        SERVER_CERT_NOT_BEFORE
+            .with_label_values(&[])
+            .set(cert.not_before);
+        SERVER_CERT_NOT_AFTER
+            .with_label_values(&[])
+            .set(cert.not_after);
+        SERVER_CERT_INFO
+            .with_label_values(&[&cert.subject, &cert.issuer])
+            .set(1.0);
+    }
+
+    fn collect_protocols(vpncmd: &str, server: &str, password: &str) {
+        match SoftEtherReader::openvpn(vpncmd, server, password) {
+            Ok(openvpn) => {
+                PROTOCOL_ENABLED
+                    .with_label_values(&["openvpn"])
+                    .set(if openvpn.enabled { 1.0 } else { 0.0 });
+                for port in &openvpn.ports {
+                    PROTOCOL_PORT_INFO
+                        .with_label_values(&["openvpn", port])
+                        .set(1.0);
+                }
+            }
+            Err(x) => println!("OpenVPN config read failed: {}", x),
+        }
+
+        match SoftEtherReader::sstp(vpncmd, server, password) {
+            Ok(sstp) => {
+                PROTOCOL_ENABLED
+                    .with_label_values(&["sstp"])
+                    .set(if sstp.enabled { 1.0 } else { 0.0 });
+            }
+            Err(x) => println!("SSTP config read failed: {}", x),
+        }
+
+        match SoftEtherReader::ipsec(vpncmd, server, password) {
+            Ok(ipsec) => {
+                PROTOCOL_ENABLED
+                    .with_label_values(&["l2tp"])
+                    .set(if ipsec.l2tp { 1.0 } else { 0.0 });
+                PROTOCOL_ENABLED
+                    .with_label_values(&["l2tp_raw"])
+                    .set(if ipsec.l2tp_raw { 1.0 } else { 0.0 });
+                PROTOCOL_ENABLED
+                    .with_label_values(&["etherip"])
+                    .set(if ipsec.etherip { 1.0 } else { 0.0 });
+                IPSEC_DEFAULT_PSK
+                    .with_label_values(&[])
+                    .set(if ipsec.default_psk { 1.0 } else { 0.0 });
+            }
+            Err(x) => println!("IPsec config read failed: {}", x),
+        }
+
+        match SoftEtherReader::etherip_clients(vpncmd, server, password) {
+            Ok(clients) => {
+                ETHERIP_CLIENTS
+                    .with_label_values(&[])
+                    .set(clients.len() as f64);
+                for client in &clients {
+                    ETHERIP_CLIENT_INFO
+                        .with_label_values(&[&client.id, &client.hub, &client.user])
+                        .set(1.0);
+                }
+            }
+            Err(x) => println!("EtherIP client list read failed: {}", x),
+        }
+    }
+
+    fn collect_dynamic_dns(vpncmd: &str, server: &str, password: &str) {
+        let ddns = match SoftEtherReader::dynamic_dns(vpncmd, server, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("Dynamic DNS status read failed: {}", x);
+                return;
+            }
+        };
+
+        let up = !ddns.hostname.is_empty() && (!ddns.ipv4.is_empty() || !ddns.ipv6.is_empty());
+        DDNS_UP
+            .with_label_values(&[])
+            .set(if up { 1.0 } else { 0.0 });
+        DDNS_INFO
+            .with_label_values(&[&ddns.hostname, &ddns.ipv4, &ddns.ipv6])
+            .set(1.0);
+    }
+
+    fn collect_vpn_azure(vpncmd: &str, server: &str, password: &str) {
+        let azure = match SoftEtherReader::vpn_azure(vpncmd, server, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("VPN Azure status read failed: {}", x);
+                return;
+            }
+        };
+
+        VPN_AZURE_ENABLED
+            .with_label_values(&[])
+            .set(if azure.enabled { 1.0 } else { 0.0 });
+        VPN_AZURE_CONNECTED
+            .with_label_values(&[])
+            .set(if azure.connected { 1.0 } else { 0.0 });
+    }
+
+    fn collect_cluster(vpncmd: &str, server: &str, password: &str) {
+        // ClusterMemberList is available on the controller only
+        let members = match SoftEtherReader::cluster_members(vpncmd, server, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("Cluster member list read failed: {}", x);
+                Exporter::collect_cluster_connection(vpncmd, server, password);
+                return;
+            }
+        };
+
+        for member in members {
+            let name = &member.hostname;
+            CLUSTER_MEMBER_CONTROLLER
+                .with_label_values(&[name])
+                .set(if member.controller { 1.0 } else { 0.0 });
+            CLUSTER_MEMBER_CONNECTED_TIMESTAMP
+                .with_label_values(&[name])
+                .set(member.connected);
+            CLUSTER_MEMBER_POINTS
+                .with_label_values(&[name])
+                .set(member.points);
+            CLUSTER_MEMBER_SESSIONS
+                .with_label_values(&[name])
+                .set(member.sessions);
+            CLUSTER_MEMBER_TCP_CONNECTIONS
+                .with_label_values(&[name])
+                .set(member.tcp_connections);
+            CLUSTER_MEMBER_HUBS
+                .with_label_values(&[name])
+                .set(member.hubs);
+
+            match SoftEtherReader::cluster_member_info(vpncmd, server, password, &member.id) {
+                Ok(info) => {
+                    CLUSTER_MEMBER_WEIGHT
+                        .with_label_values(&[name])
+                        .set(info.weight);
+                }
+                Err(x) => println!("Cluster member info read failed: {}", x),
+            }
+        }
+    }
+
+    fn collect_cluster_connection(vpncmd: &str, server: &str, password: &str) {
+        let connection = match SoftEtherReader::cluster_connection(vpncmd, server, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("Cluster connection status read failed: {}", x);
+                return;
+            }
+        };
+
+        CLUSTER_CONTROLLER_UP
+            .with_label_values(&[&connection.controller])
+            .set(if connection.online { 1.0 } else { 0.0 });
+    }
+
+    fn collect_access_lists(vpncmd: &str, server: &str, hub: &str, password: &str) {
+        let access_lists = match SoftEtherReader::hub_access_lists(vpncmd, server, hub, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("Hub access lists read failed: {}", x);
+                return;
+            }
+        };
+
+        for access_list in access_lists {
+            let enabled = if access_list.enabled { "true" } else { "false" };
+            ACCESS_LIST_INFO
+                .with_label_values(&[
+                    hub,
+                    &access_list.id,
+                    &access_list.action,
+                    &access_list.priority,
+                    enabled,
+                ])
+                .set(1.0);
+        }
+    }
+
+    fn collect_groups(
+        vpncmd: &str,
+        server: &str,
+        hub: &str,
+        password: &str,
+        users: Option<&[HubUser]>,
+    ) {
+        let groups = match SoftEtherReader::hub_groups(vpncmd, server, hub, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("Hub groups read failed: {}", x);
+                return;
+            }
+        };
+
+        for group in &groups {
+            GROUP_USERS
+                .with_label_values(&[hub, &group.name])
+                .set(group.users);
+        }
+
+        // Transfer is not exported if UserList failed, because the sum would be wrong
+        let users = match users {
+            Some(x) => x,
+            None => return,
+        };
+
+        let mut transfer_bytes = HashMap::new();
+        for group in &groups {
+            transfer_bytes.insert(group.name.clone(), 0.0);
+        }
+        for user in users {
+            if let Some(val) = transfer_bytes.get_mut(&user.group) {
+                *val += user.transfer_bytes;
+            }
+        }
+        for (group, bytes) in &transfer_bytes {
+            GROUP_TRANSFER_BYTES
+                .with_label_values(&[hub, group])
+                .set(*bytes);
+        }
+    }
+
+    fn collect_l3_switches(vpncmd: &str, server: &str, password: &str) {
+        let routers = match SoftEtherReader::routers(vpncmd, server, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("Virtual layer 3 switch list read failed: {}", x);
+                return;
+            }
+        };
+
+        for router in routers {
+            let name = &router.name;
+            L3_SWITCH_UP
+                .with_label_values(&[name])
+                .set(if router.status == "operating" {
+                    1.0
+                } else {
+                    0.0
+                });
+            L3_SWITCH_STATUS
+                .with_label_values(&[name, &router.status])
+                .set(1.0);
+            L3_SWITCH_INTERFACES
+                .with_label_values(&[name])
+                .set(router.interfaces);
+            L3_SWITCH_ROUTES
+                .with_label_values(&[name])
+                .set(router.routes);
+        }
+    }
+
+    fn collect_connections(vpncmd: &str, server: &str, password: &str) {
+        let connections = match SoftEtherReader::connections(vpncmd, server, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("Connection list read failed: {}", x);
+                return;
+            }
+        };
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|x| x.as_secs() as f64)
+            .unwrap_or(0.0);
+
+        let mut types = HashMap::new();
+        let age = CONNECTION_AGE.with_label_values(&[]);
+        for connection in &connections {
+            *types
+                .entry(connection.connection_type.clone())
+                .or_insert(0.0) += 1.0;
+
+            age.observe((now - connection.connected).max(0.0));
+        }
+
+        for (connection_type, count) in &types {
+            CONNECTIONS
+                .with_label_values(&[connection_type])
+                .set(*count);
+        }
+    }
+
+    fn collect_server_info(vpncmd: &str, server: &str, password: &str) {
+        let info = match SoftEtherReader::server_info(vpncmd, server, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("Server info read failed: {}", x);
+                return;
+            }
+        };
+
+        SERVER_INFO
+            .with_label_values(&[
+                &info.product,
+                &info.version,
+                &info.build,
+                &info.os,
+                &info.server_type,
+            ])
+            .set(1.0);
+    }
+
+    fn collect_license(vpncmd: &str, server: &str, password: &str) {
+        let license = match SoftEtherReader::license(vpncmd, server, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("License status read failed: {}", x);
+                return;
+            }
+        };
+
+        LICENSE_INFO.with_label_values(&[&license.edition]).set(1.0);
+        // Unlimited licenses have no expiration
+        if let Some(expires) = license.expires {
+            LICENSE_EXPIRES_TIMESTAMP
+                .with_label_values(&[])
+                .set(expires);
+        }
+    }
+
+    fn collect_caps(vpncmd: &str, server: &str, password: &str) {
+        let caps = match SoftEtherReader::caps(vpncmd, server, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("Capabilities read failed: {}", x);
+                return;
+            }
+        };
+
+        MAX_SESSIONS.with_label_values(&[]).set(caps.max_sessions);
+        MAX_USERS.with_label_values(&[]).set(caps.max_users);
+        for (name, supported) in &caps.flags {
+            CAPABILITY
+                .with_label_values(&[name])
+                .set(if *supported { 1.0 } else { 0.0 });
+        }
+    }
+
+    fn collect_server_cipher(vpncmd: &str, server: &str, password: &str) {
+        let cipher = match SoftEtherReader::server_cipher(vpncmd, server, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("Server cipher read failed: {}", x);
+                return;
+            }
+        };
+
+        SERVER_CIPHER_INFO.with_label_values(&[&cipher]).set(1.0);
+    }
+
+    fn collect_keep_alive(vpncmd: &str, server: &str, password: &str) {
+        let keep = match SoftEtherReader::keep_alive(vpncmd, server, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("Keep alive read failed: {}", x);
+                return;
+            }
+        };
+
+        KEEP_ALIVE_ENABLED
+            .with_label_values(&[])
+            .set(if keep.enabled { 1.0 } else { 0.0 });
+        KEEP_ALIVE_INTERVAL
+            .with_label_values(&[])
+            .set(keep.interval);
+        KEEP_ALIVE_INFO
+            .with_label_values(&[&keep.host, &keep.port, &keep.protocol])
+            .set(1.0);
+    }
+
+    fn collect_radius(vpncmd: &str, server: &str, hub: &str, password: &str) {
+        let radius = match SoftEtherReader::hub_radius(vpncmd, server, hub, password) {
+            Ok(x) => x,
+            Err(x) => {
+                println!("Hub RADIUS server read failed: {}", x);
+                return;
+            }
+        };
+
+        RADIUS_ENABLED
+            .with_label_values(&[hub])
+            .set(if radius.enabled { 1.0 } else { 0.0 });
+        if radius.enabled {
+            RADIUS_INFO
+                .with_label_values(&[hub, &radius.host, &radius.port])
+                .set(1.0);
+        }
+    }
+
+    fn collect_server(vpncmd: &str, server: &str, password: &str) {
+        let status = match SoftEtherReader::server_status(vpncmd, server, password) {
+            Ok(x) => x,
+            Err(x) => {
+                SERVER_UP.with_label_values(&[]).set(0.0);
+                println!("Server status read failed: {}", x);
+                return;
+            }
+        };
+
+        SERVER_UP.with_label_values(&[]).set(1.0);
+        SERVER_START_TIME
+            .with_label_values(&[])
+            .set(status.start_time);
+        SERVER_HUBS.with_label_values(&[]).set(status.hubs);
+        SERVER_HUBS_STATIC
+            .with_label_values(&[])
+            .set(status.hubs_static);
+        SERVER_HUBS_DYNAMIC
+            .with_label_values(&[])
+            .set(status.hubs_dynamic);
+        SERVER_SESSIONS.with_label_values(&[]).set(status.sessions);
+        SERVER_TCP_CONNECTIONS
+            .with_label_values(&[])
+            .set(status.tcp_connections);
+        SERVER_MAC_TABLES
+            .with_label_values(&[])
+            .set(status.mac_tables);
+        SERVER_IP_TABLES
+            .with_label_values(&[])
+            .set(status.ip_tables);
+        SERVER_USERS.with_label_values(&[]).set(status.users);
+        SERVER_GROUPS.with_label_values(&[]).set(status.groups);
+        SERVER_MEMORY_TOTAL_BYTES
+            .with_label_values(&[])
+            .set(status.memory_total);
+        SERVER_MEMORY_USED_BYTES
+            .with_label_values(&[])
+            .set(status.memory_used);
+        SERVER_MEMORY_FREE_BYTES
+            .with_label_values(&[])
+            .set(status.memory_free);
+        SERVER_PHYSICAL_MEMORY_TOTAL_BYTES
+            .with_label_values(&[])
+            .set(status.physical_memory_total);
+        SERVER_PHYSICAL_MEMORY_USED_BYTES
+            .with_label_values(&[])
+            .set(status.physical_memory_used);
+        SERVER_PHYSICAL_MEMORY_FREE_BYTES
+            .with_label_values(&[])
+            .set(status.physical_memory_free);
+        SERVER_OUTGOING_UNICAST_PACKETS
+            .with_label_values(&[])
+            .set(status.outgoing_unicast_packets);
+        SERVER_OUTGOING_UNICAST_BYTES
+            .with_label_values(&[])
+            .set(status.outgoing_unicast_bytes);
+        SERVER_OUTGOING_BROADCAST_PACKETS
+            .with_label_values(&[])
+            .set(status.outgoing_broadcast_packets);
+        SERVER_OUTGOING_BROADCAST_BYTES
+            .with_label_values(&[])
+            .set(status.outgoing_broadcast_bytes);
+        SERVER_INCOMING_UNICAST_PACKETS
+            .with_label_values(&[])
+            .set(status.incoming_unicast_packets);
+        SERVER_INCOMING_UNICAST_BYTES
+            .with_label_values(&[])
+            .set(status.incoming_unicast_bytes);
+        SERVER_INCOMING_BROADCAST_PACKETS
+            .with_label_values(&[])
+            .set(status.incoming_broadcast_packets);
+        SERVER_INCOMING_BROADCAST_BYTES
+            .with_label_values(&[])
+            .set(status.incoming_broadcast_bytes);
+    }
 }
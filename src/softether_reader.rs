@@ -1,8 +1,16 @@
 use csv;
+use std::env;
 use std::error::Error;
 use std::fmt;
+use std::fs;
 use std::io::Write;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::path::PathBuf;
 use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+static TEMP_DIR_COUNT: AtomicUsize = AtomicUsize::new(0);
 
 #[derive(Debug)]
 pub struct SoftEtherError {
@@ -21,59 +29,419 @@ impl Error for SoftEtherError {
     }
 }
 
+// DER TLV as ( tag, value, rest )
+type Der<'a> = (u8, &'a [u8], &'a [u8]);
+
 pub struct SoftEtherReader;
 
 impl SoftEtherReader {
+    pub fn server_status(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+    ) -> Result<ServerStatus, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, None, password, &["ServerStatusGet"])?;
+        SoftEtherReader::decode_server_status(&output)
+    }
+
+    pub fn hub_list(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+    ) -> Result<Vec<HubSummary>, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, None, password, &["HubList"])?;
+        SoftEtherReader::decode_hub_list(&output)
+    }
+
     pub fn hub_status(
         vpncmd: &str,
         server: &str,
         hub: &str,
         password: &str,
     ) -> Result<HubStatus, Box<dyn Error>> {
-        let mut child = Command::new(vpncmd)
-            .arg(server)
-            .arg("/SERVER")
-            .arg(format!("/HUB:{}", hub))
-            .arg(format!("/PASSWORD:{}", password))
-            .arg("/CSV")
-            .arg("/CMD")
-            .arg("StatusGet")
-            .stdin(Stdio::piped())
-            .stdout(Stdio::piped())
-            .spawn()?;
+        let output = SoftEtherReader::run(vpncmd, server, Some(hub), password, &["StatusGet"])?;
+        SoftEtherReader::decode_hub_status(&output)
+    }
 
-        {
-            let stdin = child.stdin.as_mut().unwrap();
-            // Input Ctrl-D to interrupt password prompt
-            stdin.write_all(&[4])?;
-        }
+    pub fn hub_sessions(
+        vpncmd: &str,
+        server: &str,
+        hub: &str,
+        password: &str,
+    ) -> Result<Vec<HubSession>, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, Some(hub), password, &["SessionList"])?;
+        SoftEtherReader::decode_hub_sessions(&output)
+    }
 
-        let output = child.wait_with_output()?;
+    pub fn hub_session_detail(
+        vpncmd: &str,
+        server: &str,
+        hub: &str,
+        password: &str,
+        session: &str,
+    ) -> Result<SessionDetail, Box<dyn Error>> {
+        let output = SoftEtherReader::run(
+            vpncmd,
+            server,
+            Some(hub),
+            password,
+            &["SessionGet", session],
+        )?;
+        SoftEtherReader::decode_hub_session_detail(&output)
+    }
 
-        if !output.status.success() {
-            let msg = String::from_utf8_lossy(output.stdout.as_slice());
-            return Err(Box::new(SoftEtherError {
-                msg: String::from(format!("vpncmd failed ( {} )", msg)),
-            }));
-        }
+    pub fn hub_mac_tables(
+        vpncmd: &str,
+        server: &str,
+        hub: &str,
+        password: &str,
+    ) -> Result<Vec<MacTableEntry>, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, Some(hub), password, &["MacTable"])?;
+        SoftEtherReader::decode_hub_mac_tables(&output)
+    }
+
+    pub fn hub_ip_tables(
+        vpncmd: &str,
+        server: &str,
+        hub: &str,
+        password: &str,
+    ) -> Result<Vec<IpTableEntry>, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, Some(hub), password, &["IpTable"])?;
+        SoftEtherReader::decode_hub_ip_tables(&output)
+    }
 
-        SoftEtherReader::decode_hub_status(&output.stdout)
+    pub fn hub_users(
+        vpncmd: &str,
+        server: &str,
+        hub: &str,
+        password: &str,
+    ) -> Result<Vec<HubUser>, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, Some(hub), password, &["UserList"])?;
+        SoftEtherReader::decode_hub_users(&output)
     }
 
-    pub fn hub_sessions(
+    pub fn hub_cascades(
         vpncmd: &str,
         server: &str,
         hub: &str,
         password: &str,
-    ) -> Result<Vec<HubSession>, Box<dyn Error>> {
-        let mut child = Command::new(vpncmd)
-            .arg(server)
-            .arg("/SERVER")
-            .arg(format!("/HUB:{}", hub))
+    ) -> Result<Vec<Cascade>, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, Some(hub), password, &["CascadeList"])?;
+        SoftEtherReader::decode_hub_cascades(&output)
+    }
+
+    pub fn hub_cascade_status(
+        vpncmd: &str,
+        server: &str,
+        hub: &str,
+        password: &str,
+        cascade: &str,
+    ) -> Result<CascadeStatus, Box<dyn Error>> {
+        let output = SoftEtherReader::run(
+            vpncmd,
+            server,
+            Some(hub),
+            password,
+            &["CascadeStatusGet", cascade],
+        )?;
+        SoftEtherReader::decode_hub_cascade_status(&output)
+    }
+
+    pub fn hub_dhcp(
+        vpncmd: &str,
+        server: &str,
+        hub: &str,
+        password: &str,
+    ) -> Result<DhcpConfig, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, Some(hub), password, &["DhcpGet"])?;
+        SoftEtherReader::decode_hub_dhcp(&output)
+    }
+
+    pub fn hub_dhcp_leases(
+        vpncmd: &str,
+        server: &str,
+        hub: &str,
+        password: &str,
+    ) -> Result<Vec<DhcpLease>, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, Some(hub), password, &["DhcpTable"])?;
+        SoftEtherReader::decode_hub_dhcp_leases(&output)
+    }
+
+    pub fn hub_nat(
+        vpncmd: &str,
+        server: &str,
+        hub: &str,
+        password: &str,
+    ) -> Result<NatConfig, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, Some(hub), password, &["NatGet"])?;
+        SoftEtherReader::decode_hub_nat(&output)
+    }
+
+    pub fn hub_nat_sessions(
+        vpncmd: &str,
+        server: &str,
+        hub: &str,
+        password: &str,
+    ) -> Result<Vec<NatSession>, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, Some(hub), password, &["NatTable"])?;
+        SoftEtherReader::decode_hub_nat_sessions(&output)
+    }
+
+    pub fn bridges(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+    ) -> Result<Vec<Bridge>, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, None, password, &["BridgeList"])?;
+        SoftEtherReader::decode_bridges(&output)
+    }
+
+    pub fn listeners(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+    ) -> Result<Vec<Listener>, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, None, password, &["ListenerList"])?;
+        SoftEtherReader::decode_listeners(&output)
+    }
+
+    pub fn server_cert(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+    ) -> Result<ServerCert, Box<dyn Error>> {
+        // vpncmd can only save the certificate to a file, so it is saved in a private directory
+        // which is unique per call and removed before returning.
+        let dir = SoftEtherReader::create_temp_dir()?;
+        let path = dir.join("server.cer");
+        let save = format!("/SAVECERT:{}", path.to_string_lossy());
+        let output =
+            SoftEtherReader::run(vpncmd, server, None, password, &["ServerCertGet", &save]);
+        let cert = fs::read(&path);
+        let _ = fs::remove_dir_all(&dir);
+        output?;
+        SoftEtherReader::decode_server_cert(&cert?)
+    }
+
+    pub fn openvpn(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+    ) -> Result<OpenVpnConfig, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, None, password, &["OpenVpnEnableGet"])?;
+        SoftEtherReader::decode_openvpn(&output)
+    }
+
+    pub fn sstp(vpncmd: &str, server: &str, password: &str) -> Result<SstpConfig, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, None, password, &["SstpEnableGet"])?;
+        SoftEtherReader::decode_sstp(&output)
+    }
+
+    pub fn ipsec(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+    ) -> Result<IpsecConfig, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, None, password, &["IPsecGet"])?;
+        SoftEtherReader::decode_ipsec(&output)
+    }
+
+    pub fn etherip_clients(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+    ) -> Result<Vec<EtherIpClient>, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, None, password, &["EtherIpClientList"])?;
+        SoftEtherReader::decode_etherip_clients(&output)
+    }
+
+    pub fn dynamic_dns(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+    ) -> Result<DynamicDns, Box<dyn Error>> {
+        let output =
+            SoftEtherReader::run(vpncmd, server, None, password, &["DynamicDnsGetStatus"])?;
+        SoftEtherReader::decode_dynamic_dns(&output)
+    }
+
+    pub fn vpn_azure(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+    ) -> Result<VpnAzure, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, None, password, &["VpnAzureGetStatus"])?;
+        SoftEtherReader::decode_vpn_azure(&output)
+    }
+
+    pub fn cluster_members(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+    ) -> Result<Vec<ClusterMember>, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, None, password, &["ClusterMemberList"])?;
+        SoftEtherReader::decode_cluster_members(&output)
+    }
+
+    pub fn cluster_member_info(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+        id: &str,
+    ) -> Result<ClusterMemberInfo, Box<dyn Error>> {
+        let output = SoftEtherReader::run(
+            vpncmd,
+            server,
+            None,
+            password,
+            &["ClusterMemberInfoGet", id],
+        )?;
+        SoftEtherReader::decode_cluster_member_info(&output)
+    }
+
+    pub fn cluster_connection(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+    ) -> Result<ClusterConnection, Box<dyn Error>> {
+        let output = SoftEtherReader::run(
+            vpncmd,
+            server,
+            None,
+            password,
+            &["ClusterConnectionStatusGet"],
+        )?;
+        SoftEtherReader::decode_cluster_connection(&output)
+    }
+
+    pub fn hub_access_lists(
+        vpncmd: &str,
+        server: &str,
+        hub: &str,
+        password: &str,
+    ) -> Result<Vec<AccessList>, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, Some(hub), password, &["AccessList"])?;
+        SoftEtherReader::decode_hub_access_lists(&output)
+    }
+
+    pub fn hub_groups(
+        vpncmd: &str,
+        server: &str,
+        hub: &str,
+        password: &str,
+    ) -> Result<Vec<HubGroup>, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, Some(hub), password, &["GroupList"])?;
+        SoftEtherReader::decode_hub_groups(&output)
+    }
+
+    pub fn routers(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+    ) -> Result<Vec<Router>, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, None, password, &["RouterList"])?;
+        SoftEtherReader::decode_routers(&output)
+    }
+
+    pub fn connections(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+    ) -> Result<Vec<Connection>, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, None, password, &["ConnectionList"])?;
+        SoftEtherReader::decode_connection_list(&output)
+    }
+
+    pub fn server_info(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+    ) -> Result<ServerInfo, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, None, password, &["ServerInfoGet"])?;
+        SoftEtherReader::decode_server_info(&output)
+    }
+
+    pub fn license(vpncmd: &str, server: &str, password: &str) -> Result<License, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, None, password, &["LicenseStatus"])?;
+        SoftEtherReader::decode_license(&output)
+    }
+
+    pub fn caps(vpncmd: &str, server: &str, password: &str) -> Result<Caps, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, None, password, &["CapsGet"])?;
+        SoftEtherReader::decode_caps(&output)
+    }
+
+    pub fn server_cipher(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+    ) -> Result<String, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, None, password, &["ServerCipherGet"])?;
+        SoftEtherReader::decode_server_cipher(&output)
+    }
+
+    pub fn keep_alive(
+        vpncmd: &str,
+        server: &str,
+        password: &str,
+    ) -> Result<KeepAlive, Box<dyn Error>> {
+        let output = SoftEtherReader::run(vpncmd, server, None, password, &["KeepGet"])?;
+        SoftEtherReader::decode_keep_alive(&output)
+    }
+
+    pub fn hub_radius(
+        vpncmd: &str,
+        server: &str,
+        hub: &str,
+        password: &str,
+    ) -> Result<Radius, Box<dyn Error>> {
+        let output =
+            SoftEtherReader::run(vpncmd, server, Some(hub), password, &["RadiusServerGet"])?;
+        SoftEtherReader::decode_hub_radius(&output)
+    }
+
+    fn create_temp_dir() -> Result<PathBuf, Box<dyn Error>> {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|x| x.subsec_nanos())
+            .unwrap_or(0);
+        let path = env::temp_dir().join(format!(
+            "softether_exporter_{}_{}_{}",
+            std::process::id(),
+            TEMP_DIR_COUNT.fetch_add(1, Ordering::SeqCst),
+            nanos
+        ));
+
+        // create fails if the path already exists, so a planted file or symlink is never used.
+        let mut builder = fs::DirBuilder::new();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::DirBuilderExt;
+            builder.mode(0o700);
+        }
+        builder.create(&path)?;
+        Ok(path)
+    }
+
+    fn run(
+        vpncmd: &str,
+        server: &str,
+        hub: Option<&str>,
+        password: &str,
+        cmd: &[&str],
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        let mut command = Command::new(vpncmd);
+        command.arg(server).arg("/SERVER");
+        if let Some(hub) = hub {
+            command.arg(format!("/HUB:{}", hub));
+        }
+        let mut child = command
             .arg(format!("/PASSWORD:{}", password))
             .arg("/CSV")
             .arg("/CMD")
-            .arg("SessionList")
+            .args(cmd)
+            // Print timestamps in UTC
+            .env("TZ", "UTC")
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .spawn()?;
@@ -89,44 +457,53 @@ impl SoftEtherReader {
         if !output.status.success() {
             let msg = String::from_utf8_lossy(output.stdout.as_slice());
             return Err(Box::new(SoftEtherError {
-                msg: String::from(format!("vpncmd failed ( {} )", msg)),
+                msg: format!("vpncmd failed ( {} )", msg),
             }));
         }
 
-        SoftEtherReader::decode_hub_sessions(&output.stdout)
+        Ok(output.stdout)
     }
 
-    fn decode_hub_status(src: &[u8]) -> Result<HubStatus, Box<dyn Error>> {
+    fn decode_server_status(src: &[u8]) -> Result<ServerStatus, Box<dyn Error>> {
         let mut rdr = csv::Reader::from_reader(src);
-        let mut status = HubStatus::new();
+        let mut status = ServerStatus::default();
 
         for entry in rdr.records() {
             let entry = entry?;
             let key = entry.get(0).unwrap_or("");
             let val = entry.get(1).unwrap_or("");
-            match key.as_ref() {
-                "仮想 HUB 名" => status.name = String::from(val),
-                "状態" => {
-                    status.online = if val == "オンライン" {
-                        true
-                    } else {
-                        false
-                    }
-                }
-                "SecureNAT 機能" => {
-                    status.secure_nat = if val == "無効" { false } else { true }
+            match key {
+                "サーバーの種類" => status.server_type = String::from(val),
+                "サーバー起動日時" => {
+                    status.start_time = SoftEtherReader::decode_datetime(val)?
                 }
+                "アクティブなソケット数" => status.tcp_connections = val.parse()?,
+                "仮想 HUB 数" => status.hubs = val.parse()?,
+                "スタティック仮想 HUB 数" => status.hubs_static = val.parse()?,
+                "ダイナミック仮想 HUB 数" => status.hubs_dynamic = val.parse()?,
                 "セッション数" => status.sessions = val.parse()?,
-                "セッション数 (クライアント)" => {
-                    status.sessions_client = val.parse()?
-                }
-                "セッション数 (ブリッジ)" => status.sessions_bridge = val.parse()?,
-                "アクセスリスト数" => status.access_lists = val.parse()?,
+                "MAC アドレステーブル数" => status.mac_tables = val.parse()?,
+                "IP アドレステーブル数" => status.ip_tables = val.parse()?,
                 "ユーザー数" => status.users = val.parse()?,
                 "グループ数" => status.groups = val.parse()?,
-                "MAC テーブル数" => status.mac_tables = val.parse()?,
-                "IP テーブル数" => status.ip_tables = val.parse()?,
-                "ログイン回数" => status.logins = val.parse()?,
+                "合計メモリサイズ" => {
+                    status.memory_total = SoftEtherReader::decode_memory(val)?
+                }
+                "使用中メモリサイズ" => {
+                    status.memory_used = SoftEtherReader::decode_memory(val)?
+                }
+                "空きメモリサイズ" => {
+                    status.memory_free = SoftEtherReader::decode_memory(val)?
+                }
+                "合計物理メモリサイズ" => {
+                    status.physical_memory_total = SoftEtherReader::decode_memory(val)?
+                }
+                "使用中物理メモリサイズ" => {
+                    status.physical_memory_used = SoftEtherReader::decode_memory(val)?
+                }
+                "空き物理メモリサイズ" => {
+                    status.physical_memory_free = SoftEtherReader::decode_memory(val)?
+                }
                 "送信ユニキャストパケット数" => {
                     status.outgoing_unicast_packets = SoftEtherReader::decode_packets(val)?
                 }
@@ -151,18 +528,29 @@ impl SoftEtherReader {
                 "受信ブロードキャスト合計サイズ" => {
                     status.incoming_broadcast_bytes = SoftEtherReader::decode_bytes(val)?
                 }
-                "Virtual Hub Name" => status.name = String::from(val),
-                "Status" => status.online = if val == "Online" { true } else { false },
-                "SecureNAT" => status.secure_nat = if val == "Disabled" { false } else { true },
-                "Sessions" => status.sessions = val.parse()?,
-                "Sessions (Client)" => status.sessions_client = val.parse()?,
-                "Sessions (Bridge)" => status.sessions_bridge = val.parse()?,
-                "Access Lists" => status.access_lists = val.parse()?,
-                "Users" => status.users = val.parse()?,
-                "Groups" => status.groups = val.parse()?,
-                "MAC Tables" => status.mac_tables = val.parse()?,
-                "IP Tables" => status.ip_tables = val.parse()?,
-                "Num Logins" => status.logins = val.parse()?,
+                "Server Type" => status.server_type = String::from(val),
+                "Server Started at" => status.start_time = SoftEtherReader::decode_datetime(val)?,
+                "Number of Active Sockets" => status.tcp_connections = val.parse()?,
+                "Number of Virtual Hubs" => status.hubs = val.parse()?,
+                "Number of Static Virtual Hubs" => status.hubs_static = val.parse()?,
+                "Number of Dynamic Virtual Hubs" => status.hubs_dynamic = val.parse()?,
+                "Number of Sessions" => status.sessions = val.parse()?,
+                "Number of MAC Address Tables" => status.mac_tables = val.parse()?,
+                "Number of IP Address Tables" => status.ip_tables = val.parse()?,
+                "Number of Users" => status.users = val.parse()?,
+                "Number of Groups" => status.groups = val.parse()?,
+                "Total Memory Size" => status.memory_total = SoftEtherReader::decode_memory(val)?,
+                "Used Memory Size" => status.memory_used = SoftEtherReader::decode_memory(val)?,
+                "Free Memory Size" => status.memory_free = SoftEtherReader::decode_memory(val)?,
+                "Total Phys. Memory Size" => {
+                    status.physical_memory_total = SoftEtherReader::decode_memory(val)?
+                }
+                "Used Phys. Memory Size" => {
+                    status.physical_memory_used = SoftEtherReader::decode_memory(val)?
+                }
+                "Free Phys. Memory Size" => {
+                    status.physical_memory_free = SoftEtherReader::decode_memory(val)?
+                }
                 "Outgoing Unicast Packets" => {
                     status.outgoing_unicast_packets = SoftEtherReader::decode_packets(val)?
                 }
@@ -187,20 +575,217 @@ impl SoftEtherReader {
                 "Incoming Broadcast Total Size" => {
                     status.incoming_broadcast_bytes = SoftEtherReader::decode_bytes(val)?
                 }
-                "虚拟 HUB 名称" => status.name = String::from(val),
-                "状态" => status.online = if val == "在线" { true } else { false },
-                "SecureNAT 机能" => {
-                    status.secure_nat = if val == "无效" { false } else { true }
+                "服务器类型" => status.server_type = String::from(val),
+                "服务器启动时间" => {
+                    status.start_time = SoftEtherReader::decode_datetime(val)?
                 }
+                "活动的 Socket 数" => status.tcp_connections = val.parse()?,
+                "虚拟 HUB 数" => status.hubs = val.parse()?,
+                "静态虚拟 HUB 数" => status.hubs_static = val.parse()?,
+                "动态虚拟 HUB 数" => status.hubs_dynamic = val.parse()?,
                 "会话数" => status.sessions = val.parse()?,
-                "会话数 (客户端)" => status.sessions_client = val.parse()?,
-                "会话数 (网桥)" => status.sessions_bridge = val.parse()?,
-                "访问列表" => status.access_lists = val.parse()?,
+                "MAC 地址表数" => status.mac_tables = val.parse()?,
+                "IP 地址表数" => status.ip_tables = val.parse()?,
                 "用户数" => status.users = val.parse()?,
                 "组数" => status.groups = val.parse()?,
-                "MAC 表数" => status.mac_tables = val.parse()?,
-                "IP 表数" => status.ip_tables = val.parse()?,
-                "登录次数" => status.logins = val.parse()?,
+                "内存总量" => status.memory_total = SoftEtherReader::decode_memory(val)?,
+                "已用内存" => status.memory_used = SoftEtherReader::decode_memory(val)?,
+                "可用内存" => status.memory_free = SoftEtherReader::decode_memory(val)?,
+                "物理内存总量" => {
+                    status.physical_memory_total = SoftEtherReader::decode_memory(val)?
+                }
+                "已用物理内存" => {
+                    status.physical_memory_used = SoftEtherReader::decode_memory(val)?
+                }
+                "可用物理内存" => {
+                    status.physical_memory_free = SoftEtherReader::decode_memory(val)?
+                }
+                "发送单播数据包" => {
+                    status.outgoing_unicast_packets = SoftEtherReader::decode_packets(val)?
+                }
+                "发送单播总量" => {
+                    status.outgoing_unicast_bytes = SoftEtherReader::decode_bytes(val)?
+                }
+                "发送广播数据包" => {
+                    status.outgoing_broadcast_packets = SoftEtherReader::decode_packets(val)?
+                }
+                "发送广播总量" => {
+                    status.outgoing_broadcast_bytes = SoftEtherReader::decode_bytes(val)?
+                }
+                "接收单播数据包" => {
+                    status.incoming_unicast_packets = SoftEtherReader::decode_packets(val)?
+                }
+                "接收单播总量" => {
+                    status.incoming_unicast_bytes = SoftEtherReader::decode_bytes(val)?
+                }
+                "接收广播数据包" => {
+                    status.incoming_broadcast_packets = SoftEtherReader::decode_packets(val)?
+                }
+                "接收广播总量" => {
+                    status.incoming_broadcast_bytes = SoftEtherReader::decode_bytes(val)?
+                }
+                _ => (),
+            }
+        }
+        Ok(status)
+    }
+
+    fn decode_hub_list(src: &[u8]) -> Result<Vec<HubSummary>, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut hubs = Vec::new();
+
+        for entry in rdr.records() {
+            // Skip a broken entry so that other hubs are still discovered
+            match entry
+                .map_err(|x| x.into())
+                .and_then(|x| SoftEtherReader::decode_hub_summary(&x))
+            {
+                Ok(hub) => hubs.push(hub),
+                Err(x) => println!("Hub list entry skipped: {}", x),
+            }
+        }
+
+        Ok(hubs)
+    }
+
+    fn decode_hub_summary(entry: &csv::StringRecord) -> Result<HubSummary, Box<dyn Error>> {
+        let name = entry.get(0).unwrap_or("");
+        let hub_type = entry.get(2).unwrap_or("");
+
+        if name.is_empty() {
+            return Err(Box::new(SoftEtherError {
+                msg: String::from("empty hub name"),
+            }));
+        }
+
+        Ok(HubSummary {
+            name: String::from(name),
+            hub_type: SoftEtherReader::decode_hub_type(hub_type),
+        })
+    }
+
+    fn decode_hub_status(src: &[u8]) -> Result<HubStatus, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut status = HubStatus::new();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let key = entry.get(0).unwrap_or("");
+            let val = entry.get(1).unwrap_or("");
+            match key.as_ref() {
+                "仮想 HUB 名" => status.name = String::from(val),
+                "状態" => {
+                    status.online = if val == "オンライン" {
+                        true
+                    } else {
+                        false
+                    }
+                }
+                "SecureNAT 機能" => {
+                    status.secure_nat = if val == "無効" { false } else { true }
+                }
+                "セッション数" => status.sessions = val.parse()?,
+                "セッション数 (クライアント)" => {
+                    status.sessions_client = val.parse()?
+                }
+                "セッション数 (ブリッジ)" => status.sessions_bridge = val.parse()?,
+                "アクセスリスト数" => status.access_lists = val.parse()?,
+                "ユーザー数" => status.users = val.parse()?,
+                "グループ数" => status.groups = val.parse()?,
+                "MAC テーブル数" => status.mac_tables = val.parse()?,
+                "IP テーブル数" => status.ip_tables = val.parse()?,
+                "ログイン回数" => status.logins = val.parse()?,
+                "最終ログイン日時" => {
+                    status.last_login = SoftEtherReader::decode_datetime(val)?
+                }
+                "最終通信日時" => {
+                    status.last_communication = SoftEtherReader::decode_datetime(val)?
+                }
+                "作成日時" => status.created = SoftEtherReader::decode_datetime(val)?,
+                "送信ユニキャストパケット数" => {
+                    status.outgoing_unicast_packets = SoftEtherReader::decode_packets(val)?
+                }
+                "送信ユニキャスト合計サイズ" => {
+                    status.outgoing_unicast_bytes = SoftEtherReader::decode_bytes(val)?
+                }
+                "送信ブロードキャストパケット数" => {
+                    status.outgoing_broadcast_packets = SoftEtherReader::decode_packets(val)?
+                }
+                "送信ブロードキャスト合計サイズ" => {
+                    status.outgoing_broadcast_bytes = SoftEtherReader::decode_bytes(val)?
+                }
+                "受信ユニキャストパケット数" => {
+                    status.incoming_unicast_packets = SoftEtherReader::decode_packets(val)?
+                }
+                "受信ユニキャスト合計サイズ" => {
+                    status.incoming_unicast_bytes = SoftEtherReader::decode_bytes(val)?
+                }
+                "受信ブロードキャストパケット数" => {
+                    status.incoming_broadcast_packets = SoftEtherReader::decode_packets(val)?
+                }
+                "受信ブロードキャスト合計サイズ" => {
+                    status.incoming_broadcast_bytes = SoftEtherReader::decode_bytes(val)?
+                }
+                "Virtual Hub Name" => status.name = String::from(val),
+                "Status" => status.online = if val == "Online" { true } else { false },
+                "SecureNAT" => status.secure_nat = if val == "Disabled" { false } else { true },
+                "Sessions" => status.sessions = val.parse()?,
+                "Sessions (Client)" => status.sessions_client = val.parse()?,
+                "Sessions (Bridge)" => status.sessions_bridge = val.parse()?,
+                "Access Lists" => status.access_lists = val.parse()?,
+                "Users" => status.users = val.parse()?,
+                "Groups" => status.groups = val.parse()?,
+                "MAC Tables" => status.mac_tables = val.parse()?,
+                "IP Tables" => status.ip_tables = val.parse()?,
+                "Num Logins" => status.logins = val.parse()?,
+                "Last Login" => status.last_login = SoftEtherReader::decode_datetime(val)?,
+                "Last Communication" => {
+                    status.last_communication = SoftEtherReader::decode_datetime(val)?
+                }
+                "Created at" => status.created = SoftEtherReader::decode_datetime(val)?,
+                "Outgoing Unicast Packets" => {
+                    status.outgoing_unicast_packets = SoftEtherReader::decode_packets(val)?
+                }
+                "Outgoing Unicast Total Size" => {
+                    status.outgoing_unicast_bytes = SoftEtherReader::decode_bytes(val)?
+                }
+                "Outgoing Broadcast Packets" => {
+                    status.outgoing_broadcast_packets = SoftEtherReader::decode_packets(val)?
+                }
+                "Outgoing Broadcast Total Size" => {
+                    status.outgoing_broadcast_bytes = SoftEtherReader::decode_bytes(val)?
+                }
+                "Incoming Unicast Packets" => {
+                    status.incoming_unicast_packets = SoftEtherReader::decode_packets(val)?
+                }
+                "Incoming Unicast Total Size" => {
+                    status.incoming_unicast_bytes = SoftEtherReader::decode_bytes(val)?
+                }
+                "Incoming Broadcast Packets" => {
+                    status.incoming_broadcast_packets = SoftEtherReader::decode_packets(val)?
+                }
+                "Incoming Broadcast Total Size" => {
+                    status.incoming_broadcast_bytes = SoftEtherReader::decode_bytes(val)?
+                }
+                "虚拟 HUB 名称" => status.name = String::from(val),
+                "状态" => status.online = if val == "在线" { true } else { false },
+                "SecureNAT 机能" => {
+                    status.secure_nat = if val == "无效" { false } else { true }
+                }
+                "会话数" => status.sessions = val.parse()?,
+                "会话数 (客户端)" => status.sessions_client = val.parse()?,
+                "会话数 (网桥)" => status.sessions_bridge = val.parse()?,
+                "访问列表" => status.access_lists = val.parse()?,
+                "用户数" => status.users = val.parse()?,
+                "组数" => status.groups = val.parse()?,
+                "MAC 表数" => status.mac_tables = val.parse()?,
+                "IP 表数" => status.ip_tables = val.parse()?,
+                "登录次数" => status.logins = val.parse()?,
+                "最后登录时间" => status.last_login = SoftEtherReader::decode_datetime(val)?,
+                "最后通信时间" => {
+                    status.last_communication = SoftEtherReader::decode_datetime(val)?
+                }
+                "创建时间" => status.created = SoftEtherReader::decode_datetime(val)?,
                 "发送单播数据包" => {
                     status.outgoing_unicast_packets = SoftEtherReader::decode_packets(val)?
                 }
@@ -267,105 +852,1796 @@ impl SoftEtherReader {
         Ok(sessions)
     }
 
-    fn decode_packets(src: &str) -> Result<f64, Box<dyn Error>> {
-        let ret = String::from(src)
-            .replace(",", "")
-            .replace(" パケット", "")
-            .replace(" packets", "")
-            .replace(" 数据包", "")
-            .parse()?;
-        Ok(ret)
+    fn decode_hub_session_detail(src: &[u8]) -> Result<SessionDetail, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut detail = SessionDetail::default();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let key = entry.get(0).unwrap_or("");
+            let val = entry.get(1).unwrap_or("");
+            match key {
+                "クライアント IP アドレス" => detail.client_ip = String::from(val),
+                "クライアントホスト名" => detail.client_hostname = String::from(val),
+                "接続開始日時" => detail.start_time = SoftEtherReader::decode_datetime(val)?,
+                "暗号化の使用" => detail.cipher = SoftEtherReader::decode_cipher(val),
+                "圧縮の使用" => detail.compression = SoftEtherReader::decode_yes_no(val),
+                "半二重 TCP コネクションモード" => {
+                    detail.half_duplex = SoftEtherReader::decode_yes_no(val)
+                }
+                "UDP 高速化機能が使用中" => {
+                    detail.udp_acceleration = SoftEtherReader::decode_yes_no(val)
+                }
+                "クライアント製品名 (申告値)" => {
+                    detail.client_product = String::from(val)
+                }
+                "クライアントバージョン (申告値)" => {
+                    detail.client_version = String::from(val)
+                }
+                "クライアント OS 名 (申告値)" => detail.client_os = String::from(val),
+                "クライアント IP アドレス (申告値)" => {
+                    detail.client_reported_ip = String::from(val)
+                }
+                "Client IP Address" => detail.client_ip = String::from(val),
+                "Client Host Name" => detail.client_hostname = String::from(val),
+                "Connection Started at" => {
+                    detail.start_time = SoftEtherReader::decode_datetime(val)?
+                }
+                "Encryption" => detail.cipher = SoftEtherReader::decode_cipher(val),
+                "Use of Compression" => detail.compression = SoftEtherReader::decode_yes_no(val),
+                "Half Duplex TCP Connection Mode" => {
+                    detail.half_duplex = SoftEtherReader::decode_yes_no(val)
+                }
+                "UDP Acceleration is Active" => {
+                    detail.udp_acceleration = SoftEtherReader::decode_yes_no(val)
+                }
+                "Client Product Name (Reported)" => detail.client_product = String::from(val),
+                "Client Version (Reported)" => detail.client_version = String::from(val),
+                "Client OS Name (Reported)" => detail.client_os = String::from(val),
+                "Client IP Address (Reported)" => detail.client_reported_ip = String::from(val),
+                "客户端 IP 地址" => detail.client_ip = String::from(val),
+                "客户端主机名" => detail.client_hostname = String::from(val),
+                "连接开始时间" => detail.start_time = SoftEtherReader::decode_datetime(val)?,
+                "加密" => detail.cipher = SoftEtherReader::decode_cipher(val),
+                "使用压缩" => detail.compression = SoftEtherReader::decode_yes_no(val),
+                "半双工 TCP 连接模式" => {
+                    detail.half_duplex = SoftEtherReader::decode_yes_no(val)
+                }
+                "UDP 加速已激活" => {
+                    detail.udp_acceleration = SoftEtherReader::decode_yes_no(val)
+                }
+                "客户端产品名 (报告值)" => detail.client_product = String::from(val),
+                "客户端版本 (报告值)" => detail.client_version = String::from(val),
+                "客户端 OS 名称 (报告值)" => detail.client_os = String::from(val),
+                "客户端 IP 地址 (报告值)" => detail.client_reported_ip = String::from(val),
+                _ => (),
+            }
+        }
+        Ok(detail)
     }
 
-    fn decode_bytes(src: &str) -> Result<f64, Box<dyn Error>> {
-        let ret = String::from(src)
-            .replace(",", "")
-            .replace(" バイト", "")
-            .replace(" bytes", "")
-            .replace(" 字节", "")
-            .parse()?;
-        Ok(ret)
+    fn decode_hub_mac_tables(src: &[u8]) -> Result<Vec<MacTableEntry>, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut entries = Vec::new();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let session = entry.get(1).unwrap_or("");
+            let vlan_id = entry.get(2).unwrap_or("");
+            let mac = entry.get(3).unwrap_or("");
+            let created = entry.get(4).unwrap_or("");
+            let updated = entry.get(5).unwrap_or("");
+
+            let entry = MacTableEntry {
+                session: String::from(session),
+                vlan_id: String::from(vlan_id),
+                mac: String::from(mac),
+                created: SoftEtherReader::decode_datetime(created)?,
+                updated: SoftEtherReader::decode_datetime(updated)?,
+            };
+
+            entries.push(entry);
+        }
+
+        Ok(entries)
     }
 
-    fn decode_connections(src: &str) -> Result<(f64, f64), Box<dyn Error>> {
-        if !src.contains('/') {
-            Ok((0.0, 0.0))
-        } else {
-            let src: Vec<_> = src.split('/').collect();
-            let ret0: f64 = src[0].trim().parse()?;
-            let ret1: f64 = src[1].trim().parse()?;
-            Ok((ret0, ret1))
+    fn decode_hub_ip_tables(src: &[u8]) -> Result<Vec<IpTableEntry>, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut entries = Vec::new();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let session = entry.get(1).unwrap_or("");
+            let ip = entry.get(2).unwrap_or("");
+            let created = entry.get(3).unwrap_or("");
+            let updated = entry.get(4).unwrap_or("");
+
+            // DHCP-assigned address is shown as "192.168.30.10 (DHCP)"
+            let dhcp = ip.contains("(DHCP)");
+            let ip = ip.replace("(DHCP)", "");
+
+            let entry = IpTableEntry {
+                session: String::from(session),
+                ip: String::from(ip.trim()),
+                dhcp,
+                created: SoftEtherReader::decode_datetime(created)?,
+                updated: SoftEtherReader::decode_datetime(updated)?,
+            };
+
+            entries.push(entry);
+        }
+
+        Ok(entries)
+    }
+
+    fn decode_hub_users(src: &[u8]) -> Result<Vec<HubUser>, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut users = Vec::new();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let name = entry.get(0).unwrap_or("");
+            let group = entry.get(2).unwrap_or("");
+            let auth_type = entry.get(4).unwrap_or("");
+            let logins = entry.get(5).unwrap_or("0");
+            let last_login = entry.get(6).unwrap_or("");
+            let expires = entry.get(7).unwrap_or("");
+            let transfer_bytes = entry.get(8).unwrap_or("0");
+            let transfer_packets = entry.get(9).unwrap_or("0");
+
+            let user = HubUser {
+                name: String::from(name),
+                group: String::from(group),
+                auth_type: SoftEtherReader::decode_auth_type(auth_type),
+                logins: logins.parse()?,
+                last_login: SoftEtherReader::decode_datetime(last_login)?,
+                expires: SoftEtherReader::decode_expires(expires)?,
+                transfer_bytes: SoftEtherReader::decode_bytes(transfer_bytes)?,
+                transfer_packets: SoftEtherReader::decode_packets(transfer_packets)?,
+            };
+
+            users.push(user);
+        }
+
+        Ok(users)
+    }
+
+    fn decode_hub_cascades(src: &[u8]) -> Result<Vec<Cascade>, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut cascades = Vec::new();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let name = entry.get(0).unwrap_or("");
+            let online = entry.get(1).unwrap_or("");
+            let server = entry.get(3).unwrap_or("");
+            let hub = entry.get(4).unwrap_or("");
+
+            let cascade = Cascade {
+                name: String::from(name),
+                online: online.starts_with("オンライン")
+                    || online.starts_with("Online")
+                    || online.starts_with("在线"),
+                server: String::from(server),
+                hub: String::from(hub),
+            };
+
+            cascades.push(cascade);
         }
+
+        Ok(cascades)
     }
+
+    fn decode_hub_cascade_status(src: &[u8]) -> Result<CascadeStatus, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut status = CascadeStatus::default();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let key = entry.get(0).unwrap_or("");
+            let val = entry.get(1).unwrap_or("");
+            match key {
+                "接続開始日時" => status.start_time = SoftEtherReader::decode_datetime(val)?,
+                "初回セッションの確立日時" => {
+                    status.first_established = SoftEtherReader::decode_datetime(val)?
+                }
+                "現在のセッションの確立日時" => {
+                    status.established = SoftEtherReader::decode_datetime(val)?
+                }
+                "確立したセッション数" => {
+                    status.established_count = SoftEtherReader::decode_count(val)?
+                }
+                "TCP コネクション数" => status.tcp_connections = val.parse()?,
+                "最大 TCP コネクション数" => status.max_tcp_connections = val.parse()?,
+                "送信データサイズ" => {
+                    status.outgoing_bytes = SoftEtherReader::decode_bytes(val)?
+                }
+                "受信データサイズ" => {
+                    status.incoming_bytes = SoftEtherReader::decode_bytes(val)?
+                }
+                "送信ユニキャストパケット数" => {
+                    status.outgoing_packets += SoftEtherReader::decode_packets(val)?
+                }
+                "送信ブロードキャストパケット数" => {
+                    status.outgoing_packets += SoftEtherReader::decode_packets(val)?
+                }
+                "受信ユニキャストパケット数" => {
+                    status.incoming_packets += SoftEtherReader::decode_packets(val)?
+                }
+                "受信ブロードキャストパケット数" => {
+                    status.incoming_packets += SoftEtherReader::decode_packets(val)?
+                }
+                "Connection Started at" => {
+                    status.start_time = SoftEtherReader::decode_datetime(val)?
+                }
+                "First Session has been Established since" => {
+                    status.first_established = SoftEtherReader::decode_datetime(val)?
+                }
+                "Current Session has been Established since" => {
+                    status.established = SoftEtherReader::decode_datetime(val)?
+                }
+                "Number of Established Sessions" => {
+                    status.established_count = SoftEtherReader::decode_count(val)?
+                }
+                "Number of TCP Connections" => status.tcp_connections = val.parse()?,
+                "Maximum Number of TCP Connections" => status.max_tcp_connections = val.parse()?,
+                "Outgoing Data Size" => status.outgoing_bytes = SoftEtherReader::decode_bytes(val)?,
+                "Incoming Data Size" => status.incoming_bytes = SoftEtherReader::decode_bytes(val)?,
+                "Outgoing Unicast Packets" => {
+                    status.outgoing_packets += SoftEtherReader::decode_packets(val)?
+                }
+                "Outgoing Broadcast Packets" => {
+                    status.outgoing_packets += SoftEtherReader::decode_packets(val)?
+                }
+                "Incoming Unicast Packets" => {
+                    status.incoming_packets += SoftEtherReader::decode_packets(val)?
+                }
+                "Incoming Broadcast Packets" => {
+                    status.incoming_packets += SoftEtherReader::decode_packets(val)?
+                }
+                "连接开始时间" => status.start_time = SoftEtherReader::decode_datetime(val)?,
+                "首次会话建立时间" => {
+                    status.first_established = SoftEtherReader::decode_datetime(val)?
+                }
+                "当前会话建立时间" => {
+                    status.established = SoftEtherReader::decode_datetime(val)?
+                }
+                "已建立会话数" => {
+                    status.established_count = SoftEtherReader::decode_count(val)?
+                }
+                "TCP 连接数" => status.tcp_connections = val.parse()?,
+                "最大 TCP 连接数" => status.max_tcp_connections = val.parse()?,
+                "发送数据大小" => status.outgoing_bytes = SoftEtherReader::decode_bytes(val)?,
+                "接收数据大小" => status.incoming_bytes = SoftEtherReader::decode_bytes(val)?,
+                "发送单播数据包" => {
+                    status.outgoing_packets += SoftEtherReader::decode_packets(val)?
+                }
+                "发送广播数据包" => {
+                    status.outgoing_packets += SoftEtherReader::decode_packets(val)?
+                }
+                "接收单播数据包" => {
+                    status.incoming_packets += SoftEtherReader::decode_packets(val)?
+                }
+                "接收广播数据包" => {
+                    status.incoming_packets += SoftEtherReader::decode_packets(val)?
+                }
+                _ => (),
+            }
+        }
+        Ok(status)
+    }
+
+    // Decode "3 Times" / "3 回" / "3 次" to 3
+    fn decode_count(src: &str) -> Result<f64, Box<dyn Error>> {
+        let ret = src
+            .chars()
+            .take_while(|c| c.is_ascii_digit() || *c == ',')
+            .filter(|c| *c != ',')
+            .collect::<String>()
+            .parse()?;
+        Ok(ret)
+    }
+
+    fn decode_hub_dhcp(src: &[u8]) -> Result<DhcpConfig, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut dhcp = DhcpConfig::default();
+        let mut start = 0.0;
+        let mut end = 0.0;
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let key = entry.get(0).unwrap_or("");
+            let val = entry.get(1).unwrap_or("");
+            match key {
+                "仮想 DHCP 機能の使用" => dhcp.enabled = SoftEtherReader::decode_yes_no(val),
+                "配布アドレス帯の開始" => start = SoftEtherReader::decode_ipv4(val)?,
+                "配布アドレス帯の終了" => end = SoftEtherReader::decode_ipv4(val)?,
+                "リース期限 (秒)" => dhcp.lease_limit = val.parse()?,
+                "Use Virtual DHCP Function" => dhcp.enabled = SoftEtherReader::decode_yes_no(val),
+                "Start Distribution Address Band" => start = SoftEtherReader::decode_ipv4(val)?,
+                "End Distribution Address Band" => end = SoftEtherReader::decode_ipv4(val)?,
+                "Lease Limit (Seconds)" => dhcp.lease_limit = val.parse()?,
+                "使用虚拟 DHCP 功能" => dhcp.enabled = SoftEtherReader::decode_yes_no(val),
+                "分配地址段开始" => start = SoftEtherReader::decode_ipv4(val)?,
+                "分配地址段结束" => end = SoftEtherReader::decode_ipv4(val)?,
+                "租约期限 (秒)" => dhcp.lease_limit = val.parse()?,
+                _ => (),
+            }
+        }
+
+        if end >= start {
+            dhcp.pool_size = end - start + 1.0;
+        }
+        Ok(dhcp)
+    }
+
+    fn decode_hub_dhcp_leases(src: &[u8]) -> Result<Vec<DhcpLease>, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut leases = Vec::new();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let expires = entry.get(2).unwrap_or("");
+            let mac = entry.get(3).unwrap_or("");
+            let ip = entry.get(4).unwrap_or("");
+            let hostname = entry.get(5).unwrap_or("");
+
+            let lease = DhcpLease {
+                mac: String::from(mac),
+                ip: String::from(ip),
+                hostname: String::from(hostname),
+                expires: SoftEtherReader::decode_datetime(expires)?,
+            };
+
+            leases.push(lease);
+        }
+
+        Ok(leases)
+    }
+
+    fn decode_hub_nat(src: &[u8]) -> Result<NatConfig, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut nat = NatConfig::default();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let key = entry.get(0).unwrap_or("");
+            let val = entry.get(1).unwrap_or("");
+            match key {
+                "仮想 NAT 機能の使用" => nat.enabled = SoftEtherReader::decode_yes_no(val),
+                "MTU 値" => nat.mtu = val.parse()?,
+                "TCP セッションのタイムアウト (秒)" => {
+                    nat.tcp_timeout = val.parse()?
+                }
+                "UDP セッションのタイムアウト (秒)" => {
+                    nat.udp_timeout = val.parse()?
+                }
+                "Use Virtual NAT Function" => nat.enabled = SoftEtherReader::decode_yes_no(val),
+                "MTU Value" => nat.mtu = val.parse()?,
+                "TCP Session Timeout (Seconds)" => nat.tcp_timeout = val.parse()?,
+                "UDP Session Timeout (Seconds)" => nat.udp_timeout = val.parse()?,
+                "使用虚拟 NAT 功能" => nat.enabled = SoftEtherReader::decode_yes_no(val),
+                "MTU 值" => nat.mtu = val.parse()?,
+                "TCP 会话超时 (秒)" => nat.tcp_timeout = val.parse()?,
+                "UDP 会话超时 (秒)" => nat.udp_timeout = val.parse()?,
+                _ => (),
+            }
+        }
+
+        Ok(nat)
+    }
+
+    fn decode_hub_nat_sessions(src: &[u8]) -> Result<Vec<NatSession>, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut sessions = Vec::new();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let protocol = entry.get(1).unwrap_or("");
+
+            let session = NatSession {
+                protocol: String::from(protocol),
+            };
+
+            sessions.push(session);
+        }
+
+        Ok(sessions)
+    }
+
+    fn decode_ipv4(src: &str) -> Result<f64, Box<dyn Error>> {
+        let octets: Vec<&str> = src.trim().split('.').collect();
+        if octets.len() != 4 {
+            return Err(format!("invalid IPv4 address: {}", src).into());
+        }
+        let mut ret = 0.0;
+        for octet in octets {
+            let octet: u8 = octet.parse()?;
+            ret = ret * 256.0 + f64::from(octet);
+        }
+        Ok(ret)
+    }
+
+    fn decode_bridges(src: &[u8]) -> Result<Vec<Bridge>, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut bridges = Vec::new();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let hub = entry.get(1).unwrap_or("");
+            let device = entry.get(2).unwrap_or("");
+            let status = entry.get(3).unwrap_or("");
+
+            let status = match status {
+                "動作中" | "Operating" | "运行中" => "operating",
+                "オフライン" | "Offline" | "离线" => "offline",
+                _ => "error",
+            };
+
+            let bridge = Bridge {
+                hub: String::from(hub),
+                device: String::from(device),
+                status: String::from(status),
+            };
+
+            bridges.push(bridge);
+        }
+
+        Ok(bridges)
+    }
+
+    fn decode_listeners(src: &[u8]) -> Result<Vec<Listener>, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut listeners = Vec::new();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let port = entry.get(0).unwrap_or("");
+            let status = entry.get(1).unwrap_or("");
+
+            // Port is shown as "TCP 443"
+            let port = port.trim_start_matches(|c: char| !c.is_ascii_digit());
+            let status = match status {
+                "受付中" | "Listening" | "监听中" => "listening",
+                "停止中" | "Stopped" | "停止" => "stopped",
+                _ => "error",
+            };
+
+            let listener = Listener {
+                port: String::from(port),
+                status: String::from(status),
+            };
+
+            listeners.push(listener);
+        }
+
+        Ok(listeners)
+    }
+
+    fn decode_server_cert(src: &[u8]) -> Result<ServerCert, Box<dyn Error>> {
+        let der = if src.starts_with(b"-----BEGIN") {
+            let pem: String = String::from_utf8_lossy(src)
+                .lines()
+                .filter(|x| !x.starts_with("-----"))
+                .collect();
+            SoftEtherReader::decode_base64(pem.trim())?
+        } else {
+            src.to_vec()
+        };
+
+        // Certificate ::= SEQUENCE { tbsCertificate, signatureAlgorithm, signature }
+        let (_, cert, _) = SoftEtherReader::decode_der(&der)?;
+        let (_, tbs, _) = SoftEtherReader::decode_der(cert)?;
+
+        // Skip version, serialNumber and signature
+        let (tag, _, rest) = SoftEtherReader::decode_der(tbs)?;
+        let (_, _, rest) = if tag == 0xa0 {
+            SoftEtherReader::decode_der(rest)?
+        } else {
+            (tag, tbs, rest)
+        };
+        let (_, _, rest) = SoftEtherReader::decode_der(rest)?;
+
+        let (_, issuer, rest) = SoftEtherReader::decode_der(rest)?;
+        let (_, validity, rest) = SoftEtherReader::decode_der(rest)?;
+        let (_, subject, _) = SoftEtherReader::decode_der(rest)?;
+
+        let (not_before_tag, not_before, rest) = SoftEtherReader::decode_der(validity)?;
+        let (not_after_tag, not_after, _) = SoftEtherReader::decode_der(rest)?;
+
+        Ok(ServerCert {
+            subject: SoftEtherReader::decode_der_name(subject)?,
+            issuer: SoftEtherReader::decode_der_name(issuer)?,
+            not_before: SoftEtherReader::decode_der_time(not_before_tag, not_before)?,
+            not_after: SoftEtherReader::decode_der_time(not_after_tag, not_after)?,
+        })
+    }
+
+    fn decode_der(src: &[u8]) -> Result<Der<'_>, Box<dyn Error>> {
+        let invalid = || SoftEtherError {
+            msg: String::from("invalid certificate"),
+        };
+
+        if src.len() < 2 {
+            return Err(Box::new(invalid()));
+        }
+        let tag = src[0];
+        let (len, head) = if src[1] < 0x80 {
+            (src[1] as usize, 2)
+        } else {
+            let num = (src[1] & 0x7f) as usize;
+            if num == 0 || num > 4 || src.len() < 2 + num {
+                return Err(Box::new(invalid()));
+            }
+            let len = src[2..2 + num]
+                .iter()
+                .fold(0, |acc, x| (acc << 8) | *x as usize);
+            (len, 2 + num)
+        };
+        if src.len() < head + len {
+            return Err(Box::new(invalid()));
+        }
+        Ok((tag, &src[head..head + len], &src[head + len..]))
+    }
+
+    // Decode Name to "C=JP, O=Example, CN=vpn.example.com"
+    fn decode_der_name(src: &[u8]) -> Result<String, Box<dyn Error>> {
+        let mut ret = Vec::new();
+        let mut rest = src;
+        while !rest.is_empty() {
+            let (_, set, next) = SoftEtherReader::decode_der(rest)?;
+            let (_, attr, _) = SoftEtherReader::decode_der(set)?;
+            let (_, oid, attr) = SoftEtherReader::decode_der(attr)?;
+            let (_, val, _) = SoftEtherReader::decode_der(attr)?;
+            let key = match oid {
+                [0x55, 0x04, 0x03] => "CN",
+                [0x55, 0x04, 0x06] => "C",
+                [0x55, 0x04, 0x07] => "L",
+                [0x55, 0x04, 0x08] => "ST",
+                [0x55, 0x04, 0x0a] => "O",
+                [0x55, 0x04, 0x0b] => "OU",
+                _ => "",
+            };
+            if !key.is_empty() {
+                ret.push(format!("{}={}", key, String::from_utf8_lossy(val)));
+            }
+            rest = next;
+        }
+        Ok(ret.join(", "))
+    }
+
+    // Decode UTCTime "200408092549Z" or GeneralizedTime "20200408092549Z" to Unix time
+    fn decode_der_time(tag: u8, src: &[u8]) -> Result<f64, Box<dyn Error>> {
+        let year_len = match tag {
+            0x17 => 2,
+            0x18 => 4,
+            _ => {
+                return Err(Box::new(SoftEtherError {
+                    msg: format!("unknown certificate time tag ( 0x{:02x} )", tag),
+                }))
+            }
+        };
+
+        let digits = match src.split_last() {
+            Some((b'Z', x)) => x,
+            _ => src,
+        };
+        if digits.len() < year_len + 10 || !digits.iter().all(|x| x.is_ascii_digit()) {
+            return Err(Box::new(SoftEtherError {
+                msg: format!(
+                    "invalid certificate time ( {} )",
+                    String::from_utf8_lossy(src)
+                ),
+            }));
+        }
+
+        let num = |x: &[u8]| x.iter().fold(0, |acc, x| acc * 10 + i64::from(x - b'0'));
+        let year = num(&digits[..year_len]);
+        let year = match year_len {
+            2 if year < 50 => 2000 + year,
+            2 => 1900 + year,
+            _ => year,
+        };
+        let src = &digits[year_len..];
+
+        Ok(SoftEtherReader::unix_time(
+            year,
+            num(&src[0..2]),
+            num(&src[2..4]),
+            num(&src[4..6]),
+            num(&src[6..8]),
+            num(&src[8..10]),
+        ))
+    }
+
+    fn decode_base64(src: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+        let mut ret = Vec::new();
+        let mut buf = 0u32;
+        let mut bits = 0;
+        for c in src.bytes() {
+            let val = match c {
+                b'A'..=b'Z' => c - b'A',
+                b'a'..=b'z' => c - b'a' + 26,
+                b'0'..=b'9' => c - b'0' + 52,
+                b'+' => 62,
+                b'/' => 63,
+                b'=' => break,
+                _ => continue,
+            };
+            buf = (buf << 6) | val as u32;
+            bits += 6;
+            if bits >= 8 {
+                bits -= 8;
+                ret.push((buf >> bits) as u8);
+                buf &= (1 << bits) - 1;
+            }
+        }
+        Ok(ret)
+    }
+
+    fn decode_openvpn(src: &[u8]) -> Result<OpenVpnConfig, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut openvpn = OpenVpnConfig::default();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let key = entry.get(0).unwrap_or("");
+            let val = entry.get(1).unwrap_or("");
+            match key {
+                "OpenVPN 互換サーバー機能を有効にする" => {
+                    openvpn.enabled = SoftEtherReader::decode_yes_no(val)
+                }
+                "OpenVPN の UDP ポート番号" => {
+                    openvpn.ports = SoftEtherReader::decode_ports(val)
+                }
+                "OpenVPN Clone Server Function Enabled" => {
+                    openvpn.enabled = SoftEtherReader::decode_yes_no(val)
+                }
+                "UDP Ports to Listen for OpenVPN" => {
+                    openvpn.ports = SoftEtherReader::decode_ports(val)
+                }
+                "启用 OpenVPN 克隆服务器功能" => {
+                    openvpn.enabled = SoftEtherReader::decode_yes_no(val)
+                }
+                "OpenVPN 监听的 UDP 端口" => {
+                    openvpn.ports = SoftEtherReader::decode_ports(val)
+                }
+                _ => (),
+            }
+        }
+
+        Ok(openvpn)
+    }
+
+    fn decode_ports(src: &str) -> Vec<String> {
+        src.split(|c: char| !c.is_ascii_digit())
+            .filter(|x| !x.is_empty())
+            .map(String::from)
+            .collect()
+    }
+
+    fn decode_sstp(src: &[u8]) -> Result<SstpConfig, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut sstp = SstpConfig::default();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let key = entry.get(0).unwrap_or("");
+            let val = entry.get(1).unwrap_or("");
+            match key {
+                "MS-SSTP VPN 互換サーバー機能を有効にする" => {
+                    sstp.enabled = SoftEtherReader::decode_yes_no(val)
+                }
+                "SSTP VPN Clone Server Function Enabled" => {
+                    sstp.enabled = SoftEtherReader::decode_yes_no(val)
+                }
+                "启用 SSTP VPN 克隆服务器功能" => {
+                    sstp.enabled = SoftEtherReader::decode_yes_no(val)
+                }
+                _ => (),
+            }
+        }
+
+        Ok(sstp)
+    }
+
+    fn decode_ipsec(src: &[u8]) -> Result<IpsecConfig, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut ipsec = IpsecConfig::default();
+
+        // "vpn" is the factory default pre-shared key
+        for entry in rdr.records() {
+            let entry = entry?;
+            let key = entry.get(0).unwrap_or("");
+            let val = entry.get(1).unwrap_or("");
+            match key {
+                "L2TP over IPsec サーバー機能を有効にする" => {
+                    ipsec.l2tp = SoftEtherReader::decode_yes_no(val)
+                }
+                "Raw L2TP サーバー機能を有効にする" => {
+                    ipsec.l2tp_raw = SoftEtherReader::decode_yes_no(val)
+                }
+                "EtherIP / L2TPv3 over IPsec サーバー機能を有効にする" => {
+                    ipsec.etherip = SoftEtherReader::decode_yes_no(val)
+                }
+                "IPsec 事前共有鍵の文字列" => ipsec.default_psk = val == "vpn",
+                "L2TP over IPsec Server Function Enabled" => {
+                    ipsec.l2tp = SoftEtherReader::decode_yes_no(val)
+                }
+                "Raw L2TP Server Function Enabled" => {
+                    ipsec.l2tp_raw = SoftEtherReader::decode_yes_no(val)
+                }
+                "EtherIP / L2TPv3 over IPsec Server Function Enabled" => {
+                    ipsec.etherip = SoftEtherReader::decode_yes_no(val)
+                }
+                "IPsec Pre-Shared Key String" => ipsec.default_psk = val == "vpn",
+                "启用 L2TP over IPsec 服务器功能" => {
+                    ipsec.l2tp = SoftEtherReader::decode_yes_no(val)
+                }
+                "启用 Raw L2TP 服务器功能" => {
+                    ipsec.l2tp_raw = SoftEtherReader::decode_yes_no(val)
+                }
+                "启用 EtherIP / L2TPv3 over IPsec 服务器功能" => {
+                    ipsec.etherip = SoftEtherReader::decode_yes_no(val)
+                }
+                "IPsec 预共享密钥字符串" => ipsec.default_psk = val == "vpn",
+                _ => (),
+            }
+        }
+
+        Ok(ipsec)
+    }
+
+    fn decode_etherip_clients(src: &[u8]) -> Result<Vec<EtherIpClient>, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut clients = Vec::new();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let id = entry.get(0).unwrap_or("");
+            let hub = entry.get(1).unwrap_or("");
+            let user = entry.get(2).unwrap_or("");
+
+            let client = EtherIpClient {
+                id: String::from(id),
+                hub: String::from(hub),
+                user: String::from(user),
+            };
+
+            clients.push(client);
+        }
+
+        Ok(clients)
+    }
+
+    // The global addresses are kept only if they are valid, because "None" is shown if not assigned
+    fn decode_dynamic_dns(src: &[u8]) -> Result<DynamicDns, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut ddns = DynamicDns::default();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let key = entry.get(0).unwrap_or("");
+            let val = entry.get(1).unwrap_or("");
+            let ipv4 = val.parse::<Ipv4Addr>().is_ok();
+            let ipv6 = val.parse::<Ipv6Addr>().is_ok();
+            match key {
+                "割り当てられたダイナミック DNS ホスト名 (完全)" => {
+                    ddns.hostname = String::from(val)
+                }
+                "グローバル IPv4 アドレス" if ipv4 => ddns.ipv4 = String::from(val),
+                "グローバル IPv6 アドレス" if ipv6 => ddns.ipv6 = String::from(val),
+                "Assigned Dynamic DNS Hostname (Full)" => ddns.hostname = String::from(val),
+                "Global IPv4 Address" if ipv4 => ddns.ipv4 = String::from(val),
+                "Global IPv6 Address" if ipv6 => ddns.ipv6 = String::from(val),
+                "分配的动态 DNS 主机名 (完整)" => ddns.hostname = String::from(val),
+                "全局 IPv4 地址" if ipv4 => ddns.ipv4 = String::from(val),
+                "全局 IPv6 地址" if ipv6 => ddns.ipv6 = String::from(val),
+                _ => (),
+            }
+        }
+
+        Ok(ddns)
+    }
+
+    fn decode_vpn_azure(src: &[u8]) -> Result<VpnAzure, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut azure = VpnAzure::default();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let key = entry.get(0).unwrap_or("");
+            let val = entry.get(1).unwrap_or("");
+            match key {
+                "VPN Azure 機能が有効" => azure.enabled = SoftEtherReader::decode_yes_no(val),
+                "VPN Azure クラウドサーバーへの接続が確立済み" => {
+                    azure.connected = SoftEtherReader::decode_yes_no(val)
+                }
+                "VPN Azure Function is Enabled" => {
+                    azure.enabled = SoftEtherReader::decode_yes_no(val)
+                }
+                "Connection to VPN Azure Cloud Server is Established" => {
+                    azure.connected = SoftEtherReader::decode_yes_no(val)
+                }
+                "VPN Azure 功能已启用" => azure.enabled = SoftEtherReader::decode_yes_no(val),
+                "与 VPN Azure 云服务器的连接已建立" => {
+                    azure.connected = SoftEtherReader::decode_yes_no(val)
+                }
+                _ => (),
+            }
+        }
+
+        Ok(azure)
+    }
+
+    fn decode_cluster_members(src: &[u8]) -> Result<Vec<ClusterMember>, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut members = Vec::new();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let id = entry.get(0).unwrap_or("");
+            let controller = entry.get(1).unwrap_or("");
+            let connected = entry.get(2).unwrap_or("");
+            let hostname = entry.get(3).unwrap_or("");
+            let points = entry.get(4).unwrap_or("");
+            let sessions = entry.get(5).unwrap_or("");
+            let tcp_connections = entry.get(6).unwrap_or("");
+            let hubs = entry.get(7).unwrap_or("");
+
+            let member = ClusterMember {
+                id: String::from(id),
+                hostname: String::from(hostname),
+                controller: controller.starts_with("コントローラ")
+                    || controller.starts_with("Controller")
+                    || controller.starts_with("控制器"),
+                connected: SoftEtherReader::decode_datetime(connected)?,
+                points: points.parse()?,
+                sessions: sessions.parse()?,
+                tcp_connections: tcp_connections.parse()?,
+                hubs: hubs.parse()?,
+            };
+
+            members.push(member);
+        }
+
+        Ok(members)
+    }
+
+    fn decode_cluster_member_info(src: &[u8]) -> Result<ClusterMemberInfo, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut info = ClusterMemberInfo::default();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let key = entry.get(0).unwrap_or("");
+            let val = entry.get(1).unwrap_or("");
+            match key {
+                "性能基準比" => info.weight = val.parse()?,
+                "Performance Standard Ratio" => info.weight = val.parse()?,
+                "性能标准比" => info.weight = val.parse()?,
+                _ => (),
+            }
+        }
+
+        Ok(info)
+    }
+
+    fn decode_cluster_connection(src: &[u8]) -> Result<ClusterConnection, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut connection = ClusterConnection::default();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let key = entry.get(0).unwrap_or("");
+            let val = entry.get(1).unwrap_or("");
+            match key {
+                "コントローラの IP アドレス" => {
+                    connection.controller = String::from(val)
+                }
+                "接続状態" => connection.online = val.starts_with("オンライン"),
+                "Controller IP Address" => connection.controller = String::from(val),
+                "Connection Status" => connection.online = val.starts_with("Online"),
+                "控制器 IP 地址" => connection.controller = String::from(val),
+                "连接状态" => connection.online = val.starts_with("在线"),
+                _ => (),
+            }
+        }
+
+        Ok(connection)
+    }
+
+    fn decode_hub_access_lists(src: &[u8]) -> Result<Vec<AccessList>, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut access_lists = Vec::new();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let id = entry.get(0).unwrap_or("");
+            let action = entry.get(1).unwrap_or("");
+            let enabled = entry.get(2).unwrap_or("");
+            let priority = entry.get(3).unwrap_or("");
+
+            let action = match action {
+                "通過" | "Pass" | "通过" => "pass",
+                _ => "discard",
+            };
+
+            let access_list = AccessList {
+                id: String::from(id),
+                action: String::from(action),
+                enabled: enabled.starts_with("有効")
+                    || enabled.starts_with("Enable")
+                    || enabled.starts_with("有效"),
+                priority: String::from(priority),
+            };
+
+            access_lists.push(access_list);
+        }
+
+        Ok(access_lists)
+    }
+
+    fn decode_hub_groups(src: &[u8]) -> Result<Vec<HubGroup>, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut groups = Vec::new();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let name = entry.get(0).unwrap_or("");
+            let users = entry.get(3).unwrap_or("0");
+
+            let group = HubGroup {
+                name: String::from(name),
+                users: users.parse()?,
+            };
+
+            groups.push(group);
+        }
+
+        Ok(groups)
+    }
+
+    fn decode_routers(src: &[u8]) -> Result<Vec<Router>, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut routers = Vec::new();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let name = entry.get(0).unwrap_or("");
+            let status = entry.get(1).unwrap_or("");
+            let interfaces = entry.get(2).unwrap_or("");
+            let routes = entry.get(3).unwrap_or("");
+
+            let status = match status {
+                "動作中" | "Operating" | "运行中" => "operating",
+                "停止中" | "Stopped" | "停止" => "stopped",
+                _ => "error",
+            };
+
+            let router = Router {
+                name: String::from(name),
+                status: String::from(status),
+                interfaces: interfaces.parse()?,
+                routes: routes.parse()?,
+            };
+
+            routers.push(router);
+        }
+
+        Ok(routers)
+    }
+
+    fn decode_connection_list(src: &[u8]) -> Result<Vec<Connection>, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut connections = Vec::new();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let connected = entry.get(2).unwrap_or("");
+            let connection_type = entry.get(3).unwrap_or("");
+
+            let connection = Connection {
+                connected: SoftEtherReader::decode_datetime(connected)?,
+                connection_type: String::from(connection_type),
+            };
+
+            connections.push(connection);
+        }
+
+        Ok(connections)
+    }
+
+    fn decode_server_info(src: &[u8]) -> Result<ServerInfo, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut info = ServerInfo::default();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let key = entry.get(0).unwrap_or("");
+            let val = entry.get(1).unwrap_or("");
+            match key {
+                "製品名" => info.product = String::from(val),
+                "バージョン" => SoftEtherReader::decode_version(val, &mut info),
+                "サーバーの種類" => info.server_type = String::from(val),
+                "OS 製品名" => info.os = String::from(val),
+                "Product Name" => info.product = String::from(val),
+                "Version" => SoftEtherReader::decode_version(val, &mut info),
+                "Server Type" => info.server_type = String::from(val),
+                "OS Product Name" => info.os = String::from(val),
+                "产品名称" => info.product = String::from(val),
+                "版本" => SoftEtherReader::decode_version(val, &mut info),
+                "服务器类型" => info.server_type = String::from(val),
+                "操作系统产品名称" => info.os = String::from(val),
+                _ => (),
+            }
+        }
+
+        Ok(info)
+    }
+
+    // Decode "Version 4.34 Build 9745   (English)" to "4.34" and "9745"
+    fn decode_version(src: &str, info: &mut ServerInfo) {
+        let mut tokens = src
+            .split(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .filter(|x| !x.is_empty());
+        info.version = String::from(tokens.next().unwrap_or(""));
+        info.build = String::from(tokens.next().unwrap_or(""));
+    }
+
+    fn decode_license(src: &[u8]) -> Result<License, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut license = License::default();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let key = entry.get(0).unwrap_or("");
+            let val = entry.get(1).unwrap_or("");
+            match key {
+                "製品エディション名" => license.edition = String::from(val),
+                "製品ライセンスの有効期限" => {
+                    license.expires = SoftEtherReader::decode_expires(val)?
+                }
+                "Product Edition Name" => license.edition = String::from(val),
+                "Product License Expiration Date" => {
+                    license.expires = SoftEtherReader::decode_expires(val)?
+                }
+                "产品版本名称" => license.edition = String::from(val),
+                "产品许可证有效期" => {
+                    license.expires = SoftEtherReader::decode_expires(val)?
+                }
+                _ => (),
+            }
+        }
+
+        Ok(license)
+    }
+
+    fn decode_caps(src: &[u8]) -> Result<Caps, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut caps = Caps::default();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let key = entry.get(0).unwrap_or("");
+            let val = entry.get(1).unwrap_or("");
+            match key {
+                "最大セッション数" => caps.max_sessions = val.parse()?,
+                "仮想 HUB あたりの最大ユーザー数" => caps.max_users = val.parse()?,
+                "Maximum Number of Sessions" => caps.max_sessions = val.parse()?,
+                "Maximum Number of Users per Virtual Hub" => caps.max_users = val.parse()?,
+                "最大会话数" => caps.max_sessions = val.parse()?,
+                "每个虚拟 HUB 的最大用户数" => caps.max_users = val.parse()?,
+                // Item name is shown without prefix if it has no description
+                "max_sessions" => caps.max_sessions = val.parse()?,
+                "max_users_per_hub" => caps.max_users = val.parse()?,
+                _ => {
+                    if let Some(name) = SoftEtherReader::decode_cap_name(key) {
+                        caps.flags
+                            .push((String::from(name), SoftEtherReader::decode_yes_no(val)));
+                    }
+                }
+            }
+        }
+
+        Ok(caps)
+    }
+
+    // Only the selected capabilities are exported with stable names,
+    // because CapsGet has hundreds of items whose names are localized
+    fn decode_cap_name(src: &str) -> Option<&'static str> {
+        match src {
+            "クラスタリングのサポート" | "Support Clustering" | "支持集群" => {
+                Some("cluster")
+            }
+            "SecureNAT のサポート" | "Support SecureNAT" | "支持 SecureNAT" => {
+                Some("securenat")
+            }
+            "syslog のサポート" | "Support Syslog" | "支持 syslog" => Some("syslog"),
+            "RADIUS 認証のサポート" | "Support RADIUS Authentication" | "支持 RADIUS 认证" => {
+                Some("radius")
+            }
+            "ローカルブリッジのサポート" | "Support Local Bridge" | "支持本地网桥" => {
+                Some("local_bridge")
+            }
+            "仮想レイヤ 3 スイッチのサポート"
+            | "Support Virtual Layer 3 Switch"
+            | "支持虚拟三层交换机" => Some("l3_switch"),
+            "ダイナミック DNS のサポート" | "Support Dynamic DNS" | "支持动态 DNS" => {
+                Some("ddns")
+            }
+            "VPN Azure のサポート" | "Support VPN Azure" | "支持 VPN Azure" => {
+                Some("vpn_azure")
+            }
+            // Item name is shown without prefix if it has no description
+            "b_support_cluster" => Some("cluster"),
+            "b_support_securenat" => Some("securenat"),
+            "b_support_syslog" => Some("syslog"),
+            "b_support_radius" => Some("radius"),
+            "b_local_bridge" => Some("local_bridge"),
+            "b_support_layer3" => Some("l3_switch"),
+            "b_support_ddns" => Some("ddns"),
+            "b_support_azure" => Some("vpn_azure"),
+            _ => None,
+        }
+    }
+
+    // ServerCipherGet is not a table even in CSV mode
+    // The current cipher is shown on the first indented line
+    fn decode_server_cipher(src: &[u8]) -> Result<String, Box<dyn Error>> {
+        let src = String::from_utf8_lossy(src);
+        match src
+            .lines()
+            .find(|x| x.starts_with(' ') && !x.trim().is_empty())
+        {
+            Some(x) => Ok(String::from(x.trim())),
+            None => Err(Box::new(SoftEtherError {
+                msg: String::from("cipher not found"),
+            })),
+        }
+    }
+
+    fn decode_keep_alive(src: &[u8]) -> Result<KeepAlive, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut keep = KeepAlive::default();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let key = entry.get(0).unwrap_or("");
+            let val = entry.get(1).unwrap_or("");
+            match key {
+                "ホスト名" => keep.host = String::from(val),
+                "ポート番号" => keep.port = String::from(val),
+                "パケット送出間隔 (秒)" => keep.interval = val.parse()?,
+                "プロトコル" => keep.protocol = val.to_lowercase(),
+                "有効" => keep.enabled = SoftEtherReader::decode_yes_no(val),
+                "Host Name" => keep.host = String::from(val),
+                "Port Number" => keep.port = String::from(val),
+                "Packet Send Interval (Seconds)" => keep.interval = val.parse()?,
+                "Protocol" => keep.protocol = val.to_lowercase(),
+                "Enabled" => keep.enabled = SoftEtherReader::decode_yes_no(val),
+                "主机名" => keep.host = String::from(val),
+                "端口号" => keep.port = String::from(val),
+                "数据包发送间隔 (秒)" => keep.interval = val.parse()?,
+                "协议" => keep.protocol = val.to_lowercase(),
+                "启用" => keep.enabled = SoftEtherReader::decode_yes_no(val),
+                _ => (),
+            }
+        }
+
+        Ok(keep)
+    }
+
+    // Only the items below are read so that the shared secret is never kept
+    fn decode_hub_radius(src: &[u8]) -> Result<Radius, Box<dyn Error>> {
+        let mut rdr = csv::Reader::from_reader(src);
+        let mut radius = Radius::default();
+
+        for entry in rdr.records() {
+            let entry = entry?;
+            let key = entry.get(0).unwrap_or("");
+            let val = entry.get(1).unwrap_or("");
+            match key {
+                "RADIUS サーバーを使用" => {
+                    radius.enabled = SoftEtherReader::decode_yes_no(val)
+                }
+                "RADIUS サーバーのホスト名" => radius.host = String::from(val),
+                "RADIUS ポート番号" => radius.port = String::from(val),
+                "Use RADIUS Server" => radius.enabled = SoftEtherReader::decode_yes_no(val),
+                "RADIUS Server Host Name" => radius.host = String::from(val),
+                "RADIUS Port Number" => radius.port = String::from(val),
+                "使用 RADIUS 服务器" => radius.enabled = SoftEtherReader::decode_yes_no(val),
+                "RADIUS 服务器主机名" => radius.host = String::from(val),
+                "RADIUS 端口号" => radius.port = String::from(val),
+                _ => (),
+            }
+        }
+
+        Ok(radius)
+    }
+
+    fn decode_packets(src: &str) -> Result<f64, Box<dyn Error>> {
+        let ret = String::from(src)
+            .replace(",", "")
+            .replace(" パケット", "")
+            .replace(" packets", "")
+            .replace(" 数据包", "")
+            .parse()?;
+        Ok(ret)
+    }
+
+    fn decode_bytes(src: &str) -> Result<f64, Box<dyn Error>> {
+        let ret = String::from(src)
+            .replace(",", "")
+            .replace(" バイト", "")
+            .replace(" bytes", "")
+            .replace(" 字节", "")
+            .parse()?;
+        Ok(ret)
+    }
+
+    fn decode_memory(src: &str) -> Result<f64, Box<dyn Error>> {
+        let src = String::from(src).replace(",", "");
+        let ret = if let Some(kb) = src.strip_suffix(" KB") {
+            kb.parse::<f64>()? * 1024.0
+        } else if let Some(mb) = src.strip_suffix(" MB") {
+            mb.parse::<f64>()? * 1024.0 * 1024.0
+        } else {
+            SoftEtherReader::decode_bytes(&src)?
+        };
+        Ok(ret)
+    }
+
+    fn decode_yes_no(src: &str) -> bool {
+        src.starts_with("はい") || src.starts_with("Yes") || src.starts_with("是")
+    }
+
+    // Decode "Enabled (Algorithm: AES128-SHA)" to "AES128-SHA"
+    fn decode_cipher(src: &str) -> String {
+        match src.rfind([':', '：']) {
+            Some(pos) => {
+                let ret = src[pos..].trim_start_matches([':', '：']);
+                String::from(ret.trim_end_matches([')', '）']).trim())
+            }
+            None => String::from(""),
+        }
+    }
+
+    fn decode_hub_type(src: &str) -> String {
+        let ret = match src {
+            "スタンドアロン" | "Standalone" | "独立" => "standalone",
+            "スタティック" | "Static" | "静态" => "static",
+            "ダイナミック" | "Dynamic" | "动态" => "dynamic",
+            _ => src,
+        };
+        String::from(ret)
+    }
+
+    fn decode_auth_type(src: &str) -> String {
+        let ret = match src {
+            "匿名認証" | "Anonymous Authentication" | "匿名认证" => "anonymous",
+            "パスワード認証" | "Password Authentication" | "密码认证" => "password",
+            "固有証明書認証" | "Individual Certificate Authentication" | "个人证书认证" => {
+                "user_cert"
+            }
+            "署名済み証明書認証" | "Signed Certificate Authentication" | "签名证书认证" => {
+                "root_cert"
+            }
+            "RADIUS 認証" | "RADIUS Authentication" | "RADIUS 认证" => "radius",
+            "NT ドメイン認証" | "NT Domain Authentication" | "NT 域认证" => "nt",
+            _ => src,
+        };
+        String::from(ret)
+    }
+
+    // Decode "2020-04-08 09:25:49" or "2020-04-08 (Wed) 09:25:49" to Unix time.
+    // Values which are not a date ( e.g. "(None)" ) are decoded to 0.
+    fn decode_datetime(src: &str) -> Result<f64, Box<dyn Error>> {
+        let src = src.trim();
+        if !src.starts_with(|c: char| c.is_ascii_digit()) {
+            return Ok(0.0);
+        }
+
+        let date = src.split_whitespace().next().unwrap_or("");
+        let time = src.split_whitespace().last().unwrap_or("");
+        let date: Vec<_> = date.split('-').collect();
+        let time: Vec<_> = time.split(':').collect();
+        if date.len() != 3 || time.len() != 3 {
+            return Err(Box::new(SoftEtherError {
+                msg: format!("invalid datetime ( {} )", src),
+            }));
+        }
+
+        let year: i64 = date[0].parse()?;
+        let month: i64 = date[1].parse()?;
+        let day: i64 = date[2].parse()?;
+        let hour: i64 = time[0].parse()?;
+        let minute: i64 = time[1].parse()?;
+        let second: i64 = time[2].parse()?;
+
+        Ok(SoftEtherReader::unix_time(
+            year, month, day, hour, minute, second,
+        ))
+    }
+
+    // Decode expiration date, which is None for values like "No Expiration"
+    fn decode_expires(src: &str) -> Result<Option<f64>, Box<dyn Error>> {
+        if src.trim().starts_with(|c: char| c.is_ascii_digit()) {
+            Ok(Some(SoftEtherReader::decode_datetime(src)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn unix_time(year: i64, month: i64, day: i64, hour: i64, minute: i64, second: i64) -> f64 {
+        // Days from 1970-01-01 in the proleptic Gregorian calendar
+        let y = if month <= 2 { year - 1 } else { year };
+        let era = if y >= 0 { y } else { y - 399 } / 400;
+        let yoe = y - era * 400;
+        let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        let days = era * 146097 + doe - 719468;
+
+        (days * 86400 + hour * 3600 + minute * 60 + second) as f64
+    }
+
+    fn decode_connections(src: &str) -> Result<(f64, f64), Box<dyn Error>> {
+        if !src.contains('/') {
+            Ok((0.0, 0.0))
+        } else {
+            let src: Vec<_> = src.split('/').collect();
+            let ret0: f64 = src[0].trim().parse()?;
+            let ret1: f64 = src[1].trim().parse()?;
+            Ok((ret0, ret1))
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ServerStatus {
+    pub server_type: String,
+    pub start_time: f64,
+    pub tcp_connections: f64,
+    pub hubs: f64,
+    pub hubs_static: f64,
+    pub hubs_dynamic: f64,
+    pub sessions: f64,
+    pub mac_tables: f64,
+    pub ip_tables: f64,
+    pub users: f64,
+    pub groups: f64,
+    pub memory_total: f64,
+    pub memory_used: f64,
+    pub memory_free: f64,
+    pub physical_memory_total: f64,
+    pub physical_memory_used: f64,
+    pub physical_memory_free: f64,
+    pub outgoing_unicast_packets: f64,
+    pub outgoing_unicast_bytes: f64,
+    pub outgoing_broadcast_packets: f64,
+    pub outgoing_broadcast_bytes: f64,
+    pub incoming_unicast_packets: f64,
+    pub incoming_unicast_bytes: f64,
+    pub incoming_broadcast_packets: f64,
+    pub incoming_broadcast_bytes: f64,
+}
+
+#[derive(Debug)]
+pub struct HubSummary {
+    pub name: String,
+    pub hub_type: String,
+}
+
+#[derive(Debug)]
+pub struct HubStatus {
+    pub name: String,
+    pub online: bool,
+    pub secure_nat: bool,
+    pub sessions: f64,
+    pub sessions_client: f64,
+    pub sessions_bridge: f64,
+    pub access_lists: f64,
+    pub users: f64,
+    pub groups: f64,
+    pub mac_tables: f64,
+    pub ip_tables: f64,
+    pub logins: f64,
+    pub last_login: f64,
+    pub last_communication: f64,
+    pub created: f64,
+    pub outgoing_unicast_packets: f64,
+    pub outgoing_unicast_bytes: f64,
+    pub outgoing_broadcast_packets: f64,
+    pub outgoing_broadcast_bytes: f64,
+    pub incoming_unicast_packets: f64,
+    pub incoming_unicast_bytes: f64,
+    pub incoming_broadcast_packets: f64,
+    pub incoming_broadcast_bytes: f64,
+}
+
+impl HubStatus {
+    pub fn new() -> HubStatus {
+        HubStatus {
+            name: String::from(""),
+            online: false,
+            secure_nat: false,
+            sessions: 0.0,
+            sessions_client: 0.0,
+            sessions_bridge: 0.0,
+            access_lists: 0.0,
+            users: 0.0,
+            groups: 0.0,
+            mac_tables: 0.0,
+            ip_tables: 0.0,
+            logins: 0.0,
+            last_login: 0.0,
+            last_communication: 0.0,
+            created: 0.0,
+            outgoing_unicast_packets: 0.0,
+            outgoing_unicast_bytes: 0.0,
+            outgoing_broadcast_packets: 0.0,
+            outgoing_broadcast_bytes: 0.0,
+            incoming_unicast_packets: 0.0,
+            incoming_unicast_bytes: 0.0,
+            incoming_broadcast_packets: 0.0,
+            incoming_broadcast_bytes: 0.0,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct HubSession {
+    pub name: String,
+    pub vlan_id: String,
+    pub location: String,
+    pub user: String,
+    pub source: String,
+    pub connections: (f64, f64),
+    pub transfer_bytes: f64,
+    pub transfer_packets: f64,
+}
+
+#[derive(Debug, Default)]
+pub struct SessionDetail {
+    pub client_ip: String,
+    pub client_reported_ip: String,
+    pub client_hostname: String,
+    pub client_product: String,
+    pub client_version: String,
+    pub client_os: String,
+    pub start_time: f64,
+    pub cipher: String,
+    pub compression: bool,
+    pub udp_acceleration: bool,
+    pub half_duplex: bool,
+}
+
+#[derive(Debug)]
+pub struct MacTableEntry {
+    pub session: String,
+    pub vlan_id: String,
+    pub mac: String,
+    pub created: f64,
+    pub updated: f64,
+}
+
+#[derive(Debug)]
+pub struct IpTableEntry {
+    pub session: String,
+    pub ip: String,
+    pub dhcp: bool,
+    pub created: f64,
+    pub updated: f64,
+}
+
+#[derive(Debug)]
+pub struct HubUser {
+    pub name: String,
+    pub group: String,
+    pub auth_type: String,
+    pub logins: f64,
+    pub last_login: f64,
+    pub expires: Option<f64>,
+    pub transfer_bytes: f64,
+    pub transfer_packets: f64,
+}
+
+#[derive(Debug)]
+pub struct Cascade {
+    pub name: String,
+    pub online: bool,
+    pub server: String,
+    pub hub: String,
+}
+
+#[derive(Debug, Default)]
+pub struct CascadeStatus {
+    pub start_time: f64,
+    pub first_established: f64,
+    pub established: f64,
+    pub established_count: f64,
+    pub tcp_connections: f64,
+    pub max_tcp_connections: f64,
+    pub outgoing_bytes: f64,
+    pub incoming_bytes: f64,
+    pub outgoing_packets: f64,
+    pub incoming_packets: f64,
+}
+
+#[derive(Debug, Default)]
+pub struct DhcpConfig {
+    pub enabled: bool,
+    pub pool_size: f64,
+    pub lease_limit: f64,
+}
+
+#[derive(Debug)]
+pub struct DhcpLease {
+    pub mac: String,
+    pub ip: String,
+    pub hostname: String,
+    pub expires: f64,
+}
+
+#[derive(Debug, Default)]
+pub struct NatConfig {
+    pub enabled: bool,
+    pub mtu: f64,
+    pub tcp_timeout: f64,
+    pub udp_timeout: f64,
+}
+
+#[derive(Debug)]
+pub struct NatSession {
+    pub protocol: String,
+}
+
+#[derive(Debug)]
+pub struct Bridge {
+    pub hub: String,
+    pub device: String,
+    pub status: String,
+}
+
+#[derive(Debug)]
+pub struct Listener {
+    pub port: String,
+    pub status: String,
+}
+
+#[derive(Debug)]
+pub struct ServerCert {
+    pub subject: String,
+    pub issuer: String,
+    pub not_before: f64,
+    pub not_after: f64,
+}
+
+#[derive(Debug, Default)]
+pub struct OpenVpnConfig {
+    pub enabled: bool,
+    pub ports: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct SstpConfig {
+    pub enabled: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct IpsecConfig {
+    pub l2tp: bool,
+    pub l2tp_raw: bool,
+    pub etherip: bool,
+    pub default_psk: bool,
+}
+
+#[derive(Debug)]
+pub struct EtherIpClient {
+    pub id: String,
+    pub hub: String,
+    pub user: String,
+}
+
+#[derive(Debug, Default)]
+pub struct DynamicDns {
+    pub hostname: String,
+    pub ipv4: String,
+    pub ipv6: String,
+}
+
+#[derive(Debug, Default)]
+pub struct VpnAzure {
+    pub enabled: bool,
+    pub connected: bool,
+}
+
+#[derive(Debug)]
+pub struct ClusterMember {
+    pub id: String,
+    pub hostname: String,
+    pub controller: bool,
+    pub connected: f64,
+    pub points: f64,
+    pub sessions: f64,
+    pub tcp_connections: f64,
+    pub hubs: f64,
+}
+
+#[derive(Debug, Default)]
+pub struct ClusterMemberInfo {
+    pub weight: f64,
+}
+
+#[derive(Debug, Default)]
+pub struct ClusterConnection {
+    pub controller: String,
+    pub online: bool,
+}
+
+#[derive(Debug)]
+pub struct AccessList {
+    pub id: String,
+    pub action: String,
+    pub enabled: bool,
+    pub priority: String,
 }
 
 #[derive(Debug)]
-pub struct HubStatus {
+pub struct HubGroup {
     pub name: String,
-    pub online: bool,
-    pub secure_nat: bool,
-    pub sessions: f64,
-    pub sessions_client: f64,
-    pub sessions_bridge: f64,
-    pub access_lists: f64,
     pub users: f64,
-    pub groups: f64,
-    pub mac_tables: f64,
-    pub ip_tables: f64,
-    pub logins: f64,
-    pub outgoing_unicast_packets: f64,
-    pub outgoing_unicast_bytes: f64,
-    pub outgoing_broadcast_packets: f64,
-    pub outgoing_broadcast_bytes: f64,
-    pub incoming_unicast_packets: f64,
-    pub incoming_unicast_bytes: f64,
-    pub incoming_broadcast_packets: f64,
-    pub incoming_broadcast_bytes: f64,
 }
 
-impl HubStatus {
-    pub fn new() -> HubStatus {
-        HubStatus {
-            name: String::from(""),
-            online: false,
-            secure_nat: false,
-            sessions: 0.0,
-            sessions_client: 0.0,
-            sessions_bridge: 0.0,
-            access_lists: 0.0,
-            users: 0.0,
-            groups: 0.0,
-            mac_tables: 0.0,
-            ip_tables: 0.0,
-            logins: 0.0,
-            outgoing_unicast_packets: 0.0,
-            outgoing_unicast_bytes: 0.0,
-            outgoing_broadcast_packets: 0.0,
-            outgoing_broadcast_bytes: 0.0,
-            incoming_unicast_packets: 0.0,
-            incoming_unicast_bytes: 0.0,
-            incoming_broadcast_packets: 0.0,
-            incoming_broadcast_bytes: 0.0,
-        }
-    }
+#[derive(Debug)]
+pub struct Router {
+    pub name: String,
+    pub status: String,
+    pub interfaces: f64,
+    pub routes: f64,
 }
 
 #[derive(Debug)]
-pub struct HubSession {
-    pub name: String,
-    pub vlan_id: String,
-    pub location: String,
-    pub user: String,
-    pub source: String,
-    pub connections: (f64, f64),
-    pub transfer_bytes: f64,
-    pub transfer_packets: f64,
+pub struct Connection {
+    pub connected: f64,
+    pub connection_type: String,
+}
+
+#[derive(Debug, Default)]
+pub struct ServerInfo {
+    pub product: String,
+    pub version: String,
+    pub build: String,
+    pub os: String,
+    pub server_type: String,
+}
+
+#[derive(Debug, Default)]
+pub struct License {
+    pub edition: String,
+    pub expires: Option<f64>,
+}
+
+#[derive(Debug, Default)]
+pub struct Caps {
+    pub max_sessions: f64,
+    pub max_users: f64,
+    pub flags: Vec<(String, bool)>,
+}
+
+#[derive(Debug, Default)]
+pub struct KeepAlive {
+    pub host: String,
+    pub port: String,
+    pub interval: f64,
+    pub protocol: String,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct Radius {
+    pub enabled: bool,
+    pub host: String,
+    pub port: String,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_server_status() {
+        let src = r#"Item,Value
+Server Type,Standalone Server
+Number of Active Sockets,12
+Number of Virtual Hubs,2
+Number of Sessions,5
+Number of MAC Address Tables,140
+Number of IP Address Tables,215
+Number of Users,3
+Number of Groups,1
+Using Client Connection Licenses (This Server),0
+Using Bridge Connection Licenses (This Server),0
+Outgoing Unicast Packets,"7,262,679,895 packets"
+Outgoing Unicast Total Size,"4,153,388,417,848 bytes"
+Outgoing Broadcast Packets,"1,756,889,863 packets"
+Outgoing Broadcast Total Size,"256,781,466,202 bytes"
+Incoming Unicast Packets,"8,840,585,104 packets"
+Incoming Unicast Total Size,"4,676,951,155,757 bytes"
+Incoming Broadcast Packets,"976,264,699 packets"
+Incoming Broadcast Total Size,"138,170,046,309 bytes"
+Server Started at,2020-04-01 (Wed) 10:04:05
+Current Time,2020-04-08 (Wed) 11:31:43
+64 bit High-Precision Logical System Clock,"609,098,000"
+Total Memory Size,"4,294,967,296 bytes"
+Used Memory Size,"1,073,741,824 bytes"
+Free Memory Size,"3,221,225,472 bytes"
+Total Phys. Memory Size,"2,147,483,648 bytes"
+Used Phys. Memory Size,"536,870,912 bytes"
+Free Phys. Memory Size,"1,610,612,736 bytes""#;
+
+        let status = SoftEtherReader::decode_server_status(src.as_bytes()).unwrap();
+        assert_eq!(status.server_type, String::from("Standalone Server"));
+        assert_eq!(status.start_time, 1585735445.0);
+        assert_eq!(status.tcp_connections, 12.0);
+        assert_eq!(status.hubs, 2.0);
+        assert_eq!(status.sessions, 5.0);
+        assert_eq!(status.mac_tables, 140.0);
+        assert_eq!(status.ip_tables, 215.0);
+        assert_eq!(status.users, 3.0);
+        assert_eq!(status.groups, 1.0);
+        assert_eq!(status.memory_total, 4294967296.0);
+        assert_eq!(status.memory_used, 1073741824.0);
+        assert_eq!(status.memory_free, 3221225472.0);
+        assert_eq!(status.physical_memory_total, 2147483648.0);
+        assert_eq!(status.physical_memory_used, 536870912.0);
+        assert_eq!(status.physical_memory_free, 1610612736.0);
+        assert_eq!(status.outgoing_unicast_packets, 7262679895.0);
+        assert_eq!(status.outgoing_unicast_bytes, 4153388417848.0);
+        assert_eq!(status.outgoing_broadcast_packets, 1756889863.0);
+        assert_eq!(status.outgoing_broadcast_bytes, 256781466202.0);
+        assert_eq!(status.incoming_unicast_packets, 8840585104.0);
+        assert_eq!(status.incoming_unicast_bytes, 4676951155757.0);
+        assert_eq!(status.incoming_broadcast_packets, 976264699.0);
+        assert_eq!(status.incoming_broadcast_bytes, 138170046309.0);
+    }
+
+    #[test]
+    fn test_hub_list() {
+        let src = r#"仮想 HUB 名,状態,種類,ユーザー,グループ,セッション,MAC テーブル,IP テーブル,ログイン回数,最終ログイン日時,最終通信日時,転送バイト数,転送パケット数
+DEFAULT,オンライン,スタンドアロン,1,0,4,134,211,18965,2020-04-08 09:25:49,2020-04-08 11:31:43,"9,225,291,086,116","18,836,418,561"
+HUB2,オフライン,スタンドアロン,0,0,0,0,0,0,(なし),(なし),0,0"#;
+
+        let hubs = SoftEtherReader::decode_hub_list(src.as_bytes()).unwrap();
+        assert_eq!(hubs.len(), 2);
+        assert_eq!(hubs[0].name, String::from("DEFAULT"));
+        assert_eq!(hubs[0].hub_type, String::from("standalone"));
+        assert_eq!(hubs[1].name, String::from("HUB2"));
+
+        let src = r#"Virtual Hub Name,Status,Type,Users,Groups,Sessions,MAC Tables,IP Tables,Num Logins,Last Login,Last Communication,Transfer Bytes,Transfer Packets
+BROKEN,Online
+,Online,Standalone,1,0,4,134,211,0,2020-04-08 09:25:49,2020-04-08 11:31:43,0,0
+HUB2,Offline,Standalone,0,0,0,0,0,0,(None),(None),0,0"#;
+
+        let hubs = SoftEtherReader::decode_hub_list(src.as_bytes()).unwrap();
+        assert_eq!(hubs.len(), 1);
+        assert_eq!(hubs[0].name, String::from("HUB2"));
+    }
+
+    #[test]
+    fn test_datetime() {
+        let datetime = SoftEtherReader::decode_datetime("1970-01-01 00:00:00").unwrap();
+        assert_eq!(datetime, 0.0);
+        let datetime = SoftEtherReader::decode_datetime("2020-04-08 09:25:49").unwrap();
+        assert_eq!(datetime, 1586337949.0);
+        let datetime = SoftEtherReader::decode_datetime("2020-02-29 (Sat) 23:59:59").unwrap();
+        assert_eq!(datetime, 1583020799.0);
+        let datetime = SoftEtherReader::decode_datetime("(None)").unwrap();
+        assert_eq!(datetime, 0.0);
+        assert!(SoftEtherReader::decode_datetime("2020-04-08").is_err());
+    }
+
     #[test]
     fn test_hub_status() {
         let src = r#"項目,値
@@ -407,6 +2683,9 @@ IP テーブル数,211
         assert_eq!(status.mac_tables, 134.0);
         assert_eq!(status.ip_tables, 211.0);
         assert_eq!(status.logins, 18965.0);
+        assert_eq!(status.last_login, 1586337949.0);
+        assert_eq!(status.last_communication, 1586345503.0);
+        assert_eq!(status.created, 1516097045.0);
         assert_eq!(status.outgoing_unicast_packets, 7262679895.0);
         assert_eq!(status.outgoing_unicast_bytes, 4153388417848.0);
         assert_eq!(status.outgoing_broadcast_packets, 1756889863.0);
@@ -417,6 +2696,47 @@ IP テーブル数,211
         assert_eq!(status.incoming_broadcast_bytes, 138170046309.0);
     }
 
+    #[test]
+    fn test_hub_status_en() {
+        let src = r#"Item,Value
+Virtual Hub Name,DEFAULT
+Status,Online
+Type,Standalone
+SecureNAT,Enabled
+Sessions,4
+Sessions (Client),3
+Sessions (Bridge),0
+Access Lists,2
+Users,1
+Groups,0
+MAC Tables,134
+IP Tables,211
+Num Logins,18965
+Last Login,2020-04-08 (Wed) 09:25:49
+Last Communication,2020-04-08 (Wed) 11:31:43
+Created at,2018-01-16 (Tue) 10:04:05
+Outgoing Unicast Packets,"7,262,679,895 packets"
+Outgoing Unicast Total Size,"4,153,388,417,848 bytes"
+Outgoing Broadcast Packets,"1,756,889,863 packets"
+Outgoing Broadcast Total Size,"256,781,466,202 bytes"
+Incoming Unicast Packets,"8,840,585,104 packets"
+Incoming Unicast Total Size,"4,676,951,155,757 bytes"
+Incoming Broadcast Packets,"976,264,699 packets"
+Incoming Broadcast Total Size,"138,170,046,309 bytes""#;
+
+        let status = SoftEtherReader::decode_hub_status(src.as_bytes()).unwrap();
+        assert_eq!(status.name, String::from("DEFAULT"));
+        assert!(status.online);
+        assert!(status.secure_nat);
+        assert_eq!(status.sessions, 4.0);
+        assert_eq!(status.access_lists, 2.0);
+        assert_eq!(status.logins, 18965.0);
+        assert_eq!(status.last_login, 1586337949.0);
+        assert_eq!(status.last_communication, 1586345503.0);
+        assert_eq!(status.created, 1516097045.0);
+        assert_eq!(status.incoming_broadcast_bytes, 138170046309.0);
+    }
+
     #[test]
     fn test_hub_session() {
         let src = r#"セッション名,VLAN ID,場所,ユーザー名,接続元ホスト名,TCP コネクション,転送バイト数,転送パケット数
@@ -441,4 +2761,728 @@ SID-XXXX-1047,－,ローカルセッション,xxxx,xxx.example.com,2 / 2,"82,691
         assert_eq!(sessions[1].transfer_bytes, 82691861.0);
         assert_eq!(sessions[1].transfer_packets, 322784.0);
     }
+
+    #[test]
+    fn test_hub_session_detail() {
+        let src = r#"Item,Value
+Session Name,SID-XXXX-1047
+VLAN ID,-
+Server Session Name,SID-XXXX-1047
+Client IP Address,203.0.113.10
+Client Host Name,xxx.example.com
+User Name (Authentication),xxxx
+User Name (Database),xxxx
+Server Product Name,SoftEther VPN Server (64 bit)
+Server Version,4.29
+Server Build,Build 9680
+Connection Started at,2020-04-08 (Wed) 09:25:49
+First Session has been Established since,2020-04-08 (Wed) 09:25:49
+Current Session has been Established since,2020-04-08 (Wed) 09:25:50
+Half Duplex TCP Connection Mode,No (Full Duplex Mode)
+VoIP / QoS Function,Enabled
+Number of TCP Connections,2
+Maximum Number of TCP Connections,2
+Encryption,Enabled (Algorithm: AES128-SHA)
+Use of Compression,No (No Compression)
+UDP Acceleration is Supported,Yes
+UDP Acceleration is Active,Yes
+Client Product Name (Reported),SoftEther VPN Client (64 bit)
+Client Version (Reported),4.29
+Client Build Number (Reported),Build 9680
+Client OS Name (Reported),Windows 10
+Client Host Name (Reported),DESKTOP-XXXX
+Client IP Address (Reported),192.168.0.10"#;
+
+        let detail = SoftEtherReader::decode_hub_session_detail(src.as_bytes()).unwrap();
+        assert_eq!(detail.client_ip, String::from("203.0.113.10"));
+        assert_eq!(detail.client_reported_ip, String::from("192.168.0.10"));
+        assert_eq!(detail.client_hostname, String::from("xxx.example.com"));
+        assert_eq!(
+            detail.client_product,
+            String::from("SoftEther VPN Client (64 bit)")
+        );
+        assert_eq!(detail.client_version, String::from("4.29"));
+        assert_eq!(detail.client_os, String::from("Windows 10"));
+        assert_eq!(detail.start_time, 1586337949.0);
+        assert_eq!(detail.cipher, String::from("AES128-SHA"));
+        assert!(!detail.compression);
+        assert!(detail.udp_acceleration);
+        assert!(!detail.half_duplex);
+    }
+
+    #[test]
+    fn test_hub_mac_tables() {
+        let src = r#"ID,セッション名,VLAN ID,MAC アドレス,作成日時,更新日時,登録場所
+1,SID-LOCALBRIDGE-1,－,00-AC-12-34-56-78,2020-04-08 09:25:49,2020-04-08 11:31:43,ローカル
+2,SID-XXXX-1047,1,5E-12-34-56-78-9A,2020-04-08 10:00:00,2020-04-08 11:31:40,ローカル"#;
+
+        let entries = SoftEtherReader::decode_hub_mac_tables(src.as_bytes()).unwrap();
+        assert_eq!(entries[0].session, String::from("SID-LOCALBRIDGE-1"));
+        assert_eq!(entries[0].vlan_id, String::from("－"));
+        assert_eq!(entries[0].mac, String::from("00-AC-12-34-56-78"));
+        assert_eq!(entries[0].created, 1586337949.0);
+        assert_eq!(entries[0].updated, 1586345503.0);
+        assert_eq!(entries[1].session, String::from("SID-XXXX-1047"));
+        assert_eq!(entries[1].vlan_id, String::from("1"));
+        assert_eq!(entries[1].mac, String::from("5E-12-34-56-78-9A"));
+    }
+
+    #[test]
+    fn test_hub_ip_tables() {
+        let src = r#"ID,セッション名,IP アドレス,作成日時,更新日時,登録場所
+1,SID-XXXX-1047,192.168.30.10 (DHCP),2020-04-08 09:25:49,2020-04-08 11:31:43,ローカル
+2,SID-LOCALBRIDGE-1,192.168.30.1,2020-04-08 10:00:00,2020-04-08 11:31:40,ローカル"#;
+
+        let entries = SoftEtherReader::decode_hub_ip_tables(src.as_bytes()).unwrap();
+        assert_eq!(entries[0].session, String::from("SID-XXXX-1047"));
+        assert_eq!(entries[0].ip, String::from("192.168.30.10"));
+        assert!(entries[0].dhcp);
+        assert_eq!(entries[0].created, 1586337949.0);
+        assert_eq!(entries[0].updated, 1586345503.0);
+        assert_eq!(entries[1].session, String::from("SID-LOCALBRIDGE-1"));
+        assert_eq!(entries[1].ip, String::from("192.168.30.1"));
+        assert!(!entries[1].dhcp);
+    }
+
+    #[test]
+    fn test_hub_users() {
+        let src = r#"User Name,Full Name,Group Name,Description,Auth Method,Num Logins,Last Login,Expiration Date,Transfer Bytes,Transfer Packets
+xxxx,Xxx Xxx,sales,,Password Authentication,1234,2020-04-08 (Wed) 09:25:49,2020-12-31 (Thu) 23:59:59,"82,691,861","322,784"
+yyyy,,-,,RADIUS Authentication,0,(None),No Expiration,0,0"#;
+
+        let users = SoftEtherReader::decode_hub_users(src.as_bytes()).unwrap();
+        assert_eq!(users[0].name, String::from("xxxx"));
+        assert_eq!(users[0].group, String::from("sales"));
+        assert_eq!(users[0].auth_type, String::from("password"));
+        assert_eq!(users[0].logins, 1234.0);
+        assert_eq!(users[0].last_login, 1586337949.0);
+        assert_eq!(users[0].expires, Some(1609459199.0));
+        assert_eq!(users[0].transfer_bytes, 82691861.0);
+        assert_eq!(users[0].transfer_packets, 322784.0);
+        assert_eq!(users[1].name, String::from("yyyy"));
+        assert_eq!(users[1].group, String::from("-"));
+        assert_eq!(users[1].auth_type, String::from("radius"));
+        assert_eq!(users[1].logins, 0.0);
+        assert_eq!(users[1].last_login, 0.0);
+        assert_eq!(users[1].expires, None);
+    }
+
+    #[test]
+    fn test_hub_cascades() {
+        let src = r#"Setting Name,Status,Established at,Destination VPN Server,Virtual Hub
+LINK1,Online (Established),2020-04-08 (Wed) 09:25:49,vpn1.example.com:443 (Direct TCP/IP Connection),HUB1
+LINK2,Offline,,vpn2.example.com:443 (Direct TCP/IP Connection),HUB2"#;
+
+        let cascades = SoftEtherReader::decode_hub_cascades(src.as_bytes()).unwrap();
+        assert_eq!(cascades[0].name, String::from("LINK1"));
+        assert!(cascades[0].online);
+        assert_eq!(
+            cascades[0].server,
+            String::from("vpn1.example.com:443 (Direct TCP/IP Connection)")
+        );
+        assert_eq!(cascades[0].hub, String::from("HUB1"));
+        assert_eq!(cascades[1].name, String::from("LINK2"));
+        assert!(!cascades[1].online);
+    }
+
+    #[test]
+    fn test_hub_cascade_status() {
+        let src = r#"Item,Value
+VPN Connection Setting Name,LINK1
+Session Status,Connection Completed (Session Established)
+Server Name,vpn1.example.com
+Port Number,TCP Port 443
+Server Product Name,SoftEther VPN Server (64 bit)
+Server Version,4.29
+Server Build,Build 9680
+Connection Started at,2020-04-08 (Wed) 09:25:49
+First Session has been Established since,2020-04-08 (Wed) 09:25:49
+Current Session has been Established since,2020-04-08 (Wed) 11:31:43
+Number of Established Sessions,3 Times
+Half Duplex TCP Connection Mode,No (Full Duplex Mode)
+Number of TCP Connections,2
+Maximum Number of TCP Connections,8
+Outgoing Data Size,"82,691,861 bytes"
+Incoming Data Size,"294,035,917 bytes"
+Outgoing Unicast Packets,"322,784 packets"
+Outgoing Unicast Total Size,"82,000,000 bytes"
+Outgoing Broadcast Packets,"1,000 packets"
+Outgoing Broadcast Total Size,"691,861 bytes"
+Incoming Unicast Packets,"1,380,000 packets"
+Incoming Unicast Total Size,"294,000,000 bytes"
+Incoming Broadcast Packets,"393 packets"
+Incoming Broadcast Total Size,"35,917 bytes""#;
+
+        let status = SoftEtherReader::decode_hub_cascade_status(src.as_bytes()).unwrap();
+        assert_eq!(status.start_time, 1586337949.0);
+        assert_eq!(status.first_established, 1586337949.0);
+        assert_eq!(status.established, 1586345503.0);
+        assert_eq!(status.established_count, 3.0);
+        assert_eq!(status.tcp_connections, 2.0);
+        assert_eq!(status.max_tcp_connections, 8.0);
+        assert_eq!(status.outgoing_bytes, 82691861.0);
+        assert_eq!(status.incoming_bytes, 294035917.0);
+        assert_eq!(status.outgoing_packets, 323784.0);
+        assert_eq!(status.incoming_packets, 1380393.0);
+    }
+
+    #[test]
+    fn test_hub_dhcp() {
+        let src = r#"Item,Value
+Use Virtual DHCP Function,Yes
+Start Distribution Address Band,192.168.30.10
+End Distribution Address Band,192.168.30.200
+Subnet Mask,255.255.255.0
+Lease Limit (Seconds),7200
+Default Gateway Address,192.168.30.1
+DNS Server Address 1,192.168.30.1
+DNS Server Address 2,None
+Domain Name,
+Save NAT and DHCP Operation Log,Yes"#;
+
+        let dhcp = SoftEtherReader::decode_hub_dhcp(src.as_bytes()).unwrap();
+        assert!(dhcp.enabled);
+        assert_eq!(dhcp.pool_size, 191.0);
+        assert_eq!(dhcp.lease_limit, 7200.0);
+    }
+
+    #[test]
+    fn test_hub_dhcp_leases() {
+        let src = r#"ID,Leased at,Expiration Date,MAC Address,Allocated IP,Client Host Name
+1,2020-04-08 (Wed) 09:25:49,2020-04-08 (Wed) 11:25:49,5E-12-34-56-78-9A,192.168.30.10,DESKTOP-XXXX
+2,2020-04-08 (Wed) 10:00:00,2020-04-08 (Wed) 12:00:00,5E-12-34-56-78-9B,192.168.30.11,"#;
+
+        let leases = SoftEtherReader::decode_hub_dhcp_leases(src.as_bytes()).unwrap();
+        assert_eq!(leases.len(), 2);
+        assert_eq!(leases[0].mac, String::from("5E-12-34-56-78-9A"));
+        assert_eq!(leases[0].ip, String::from("192.168.30.10"));
+        assert_eq!(leases[0].hostname, String::from("DESKTOP-XXXX"));
+        assert_eq!(leases[0].expires, 1586345149.0);
+        assert_eq!(leases[1].hostname, String::from(""));
+    }
+
+    #[test]
+    fn test_hub_nat() {
+        let src = r#"Item,Value
+Use Virtual NAT Function,Yes
+MTU Value,1500
+TCP Session Timeout (Seconds),1800
+UDP Session Timeout (Seconds),60
+Save NAT and DHCP Operation Log,Yes"#;
+
+        let nat = SoftEtherReader::decode_hub_nat(src.as_bytes()).unwrap();
+        assert!(nat.enabled);
+        assert_eq!(nat.mtu, 1500.0);
+        assert_eq!(nat.tcp_timeout, 1800.0);
+        assert_eq!(nat.udp_timeout, 60.0);
+    }
+
+    #[test]
+    fn test_decode_ipv4() {
+        assert_eq!(
+            SoftEtherReader::decode_ipv4("192.168.30.10").unwrap(),
+            3232243210.0
+        );
+        assert!(SoftEtherReader::decode_ipv4("192.168.30").is_err());
+        assert!(SoftEtherReader::decode_ipv4("10").is_err());
+        assert!(SoftEtherReader::decode_ipv4("192.168.30.10.1").is_err());
+        assert!(SoftEtherReader::decode_ipv4("None").is_err());
+    }
+
+    #[test]
+    fn test_hub_nat_sessions() {
+        let src = r#"ID,Protocol,Source Address,Source Host Name,Destination Address,Destination Host Name,Started at,Last Communication at,Transfer Size,Received Size,TCP Status
+1,TCP,192.168.30.10:50000,DESKTOP-XXXX,203.0.113.1:443,www.example.com,2020-04-08 (Wed) 09:25:49,2020-04-08 (Wed) 09:25:50,"1,024 bytes","2,048 bytes",Connected
+2,UDP,192.168.30.10:50001,DESKTOP-XXXX,203.0.113.2:123,ntp.example.com,2020-04-08 (Wed) 09:25:49,2020-04-08 (Wed) 09:25:50,48 bytes,48 bytes,
+3,DNS,192.168.30.11:50002,,192.168.30.1:53,,2020-04-08 (Wed) 09:25:49,2020-04-08 (Wed) 09:25:50,64 bytes,128 bytes,"#;
+
+        let sessions = SoftEtherReader::decode_hub_nat_sessions(src.as_bytes()).unwrap();
+        assert_eq!(sessions.len(), 3);
+        assert_eq!(sessions[0].protocol, String::from("TCP"));
+        assert_eq!(sessions[1].protocol, String::from("UDP"));
+        assert_eq!(sessions[2].protocol, String::from("DNS"));
+    }
+
+    #[test]
+    fn test_bridges() {
+        let src = r#"番号,仮想 HUB 名,ブリッジ先 LAN カードまたは tap デバイス名,状態
+1,DEFAULT,eth1,動作中
+2,HUB2,eth2,オフライン
+3,HUB3,tap_vpn,エラー発生"#;
+
+        let bridges = SoftEtherReader::decode_bridges(src.as_bytes()).unwrap();
+        assert_eq!(bridges[0].hub, String::from("DEFAULT"));
+        assert_eq!(bridges[0].device, String::from("eth1"));
+        assert_eq!(bridges[0].status, String::from("operating"));
+        assert_eq!(bridges[1].status, String::from("offline"));
+        assert_eq!(bridges[2].hub, String::from("HUB3"));
+        assert_eq!(bridges[2].device, String::from("tap_vpn"));
+        assert_eq!(bridges[2].status, String::from("error"));
+    }
+
+    #[test]
+    fn test_listeners() {
+        let src = r#"Port Number,Status
+TCP 443,Listening
+TCP 992,Listening
+TCP 1194,Error
+TCP 5555,Stopped"#;
+
+        let listeners = SoftEtherReader::decode_listeners(src.as_bytes()).unwrap();
+        assert_eq!(listeners.len(), 4);
+        assert_eq!(listeners[0].port, String::from("443"));
+        assert_eq!(listeners[0].status, String::from("listening"));
+        assert_eq!(listeners[2].port, String::from("1194"));
+        assert_eq!(listeners[2].status, String::from("error"));
+        assert_eq!(listeners[3].port, String::from("5555"));
+        assert_eq!(listeners[3].status, String::from("stopped"));
+    }
+
+    #[test]
+    fn test_server_cert() {
+        let src = r#"-----BEGIN CERTIFICATE-----
+MIIByDCCAW2gAwIBAgIUP2YBoWF+GsZfshvgZeD4jUXCT0swCgYIKoZIzj0EAwIw
+OTELMAkGA1UEBhMCSlAxEDAOBgNVBAoMB0V4YW1wbGUxGDAWBgNVBAMMD3Zwbi5l
+eGFtcGxlLmNvbTAeFw0yMDA0MDgwOTI1NDlaFw0zMDA0MDYwOTI1NDlaMDkxCzAJ
+BgNVBAYTAkpQMRAwDgYDVQQKDAdFeGFtcGxlMRgwFgYDVQQDDA92cG4uZXhhbXBs
+ZS5jb20wWTATBgcqhkjOPQIBBggqhkjOPQMBBwNCAATOT0t/uI24jFKIAjTsNtdg
+xMQnIOf3+tOknzpwjyUfWRwfshKcEzjpd9UVyPB0fbXjp7bJpP/0A/U/tahSXbVX
+o1MwUTAdBgNVHQ4EFgQUZFnKPTqpzjcDXUwHR3zOKmcOCPEwHwYDVR0jBBgwFoAU
+ZFnKPTqpzjcDXUwHR3zOKmcOCPEwDwYDVR0TAQH/BAUwAwEB/zAKBggqhkjOPQQD
+AgNJADBGAiEAhjovQf66AG+m8AjuROKhHFzRe3FC2j8kd6sj42kYxwgCIQDO2z4q
+NRLt7Uk79fzxvJvHF2MCRtpOPPZH/N3rE81HwQ==
+-----END CERTIFICATE-----"#;
+
+        let cert = SoftEtherReader::decode_server_cert(src.as_bytes()).unwrap();
+        assert_eq!(
+            cert.subject,
+            String::from("C=JP, O=Example, CN=vpn.example.com")
+        );
+        assert_eq!(
+            cert.issuer,
+            String::from("C=JP, O=Example, CN=vpn.example.com")
+        );
+        assert_eq!(cert.not_before, 1586337949.0);
+        assert_eq!(cert.not_after, 1901697949.0);
+
+        let pem: String = src.lines().filter(|x| !x.starts_with("-----")).collect();
+        let der = SoftEtherReader::decode_base64(&pem).unwrap();
+        for len in 0..der.len() {
+            assert!(SoftEtherReader::decode_server_cert(&der[..len]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_server_cert_invalid() {
+        assert!(SoftEtherReader::decode_server_cert(b"").is_err());
+        assert!(SoftEtherReader::decode_server_cert(b"\x30").is_err());
+        assert!(SoftEtherReader::decode_server_cert(b"\x30\x82\x01").is_err());
+        assert!(SoftEtherReader::decode_server_cert(b"\x30\x84\xff\xff\xff\xff").is_err());
+        assert!(SoftEtherReader::decode_server_cert(b"\x30\x03\x30\x01\x02").is_err());
+        assert!(SoftEtherReader::decode_server_cert(b"garbage").is_err());
+        assert!(SoftEtherReader::decode_server_cert(
+            b"-----BEGIN CERTIFICATE-----\nMIIByDCCAW2g\n-----END CERTIFICATE-----"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_create_temp_dir() {
+        let dir1 = SoftEtherReader::create_temp_dir().unwrap();
+        let dir2 = SoftEtherReader::create_temp_dir().unwrap();
+        assert_ne!(dir1, dir2);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&dir1).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o700);
+        }
+        fs::remove_dir(&dir1).unwrap();
+        fs::remove_dir(&dir2).unwrap();
+    }
+
+    #[test]
+    fn test_decode_der_time() {
+        assert_eq!(
+            SoftEtherReader::decode_der_time(0x17, b"200408092549Z").unwrap(),
+            1586337949.0
+        );
+        assert_eq!(
+            SoftEtherReader::decode_der_time(0x18, b"20200408092549Z").unwrap(),
+            1586337949.0
+        );
+        assert!(SoftEtherReader::decode_der_time(0x0c, b"200408092549Z").is_err());
+        assert!(SoftEtherReader::decode_der_time(0x17, b"").is_err());
+        assert!(SoftEtherReader::decode_der_time(0x17, b"Z").is_err());
+        assert!(SoftEtherReader::decode_der_time(0x18, b"2020040809Z").is_err());
+        assert!(SoftEtherReader::decode_der_time(0x17, b"20\xe3\x81\x8208092549Z").is_err());
+        assert!(SoftEtherReader::decode_der_time(0x17, b"2004080925+9Z").is_err());
+    }
+
+    #[test]
+    fn test_openvpn() {
+        let src = r#"Item,Value
+OpenVPN Clone Server Function Enabled,Yes
+UDP Ports to Listen for OpenVPN,"1194, 1195""#;
+
+        let openvpn = SoftEtherReader::decode_openvpn(src.as_bytes()).unwrap();
+        assert!(openvpn.enabled);
+        assert_eq!(
+            openvpn.ports,
+            vec![String::from("1194"), String::from("1195")]
+        );
+    }
+
+    #[test]
+    fn test_sstp() {
+        let src = r#"Item,Value
+SSTP VPN Clone Server Function Enabled,Yes"#;
+
+        let sstp = SoftEtherReader::decode_sstp(src.as_bytes()).unwrap();
+        assert!(sstp.enabled);
+
+        let src = r#"項目,値
+MS-SSTP VPN 互換サーバー機能を有効にする,いいえ"#;
+
+        let sstp = SoftEtherReader::decode_sstp(src.as_bytes()).unwrap();
+        assert!(!sstp.enabled);
+    }
+
+    #[test]
+    fn test_ipsec() {
+        let src = r#"Item,Value
+L2TP over IPsec Server Function Enabled,Yes
+Raw L2TP Server Function Enabled,No
+EtherIP / L2TPv3 over IPsec Server Function Enabled,Yes
+IPsec Pre-Shared Key String,vpn
+Default Virtual HUB Name for L2TP,DEFAULT"#;
+
+        let ipsec = SoftEtherReader::decode_ipsec(src.as_bytes()).unwrap();
+        assert!(ipsec.l2tp);
+        assert!(!ipsec.l2tp_raw);
+        assert!(ipsec.etherip);
+        assert!(ipsec.default_psk);
+
+        let src = r#"Item,Value
+Default Virtual HUB Name for L2TP,DEFAULT
+IPsec Pre-Shared Key String,secret
+EtherIP / L2TPv3 over IPsec Server Function Enabled,No
+L2TP over IPsec Server Function Enabled,Yes"#;
+
+        let ipsec = SoftEtherReader::decode_ipsec(src.as_bytes()).unwrap();
+        assert!(ipsec.l2tp);
+        assert!(!ipsec.l2tp_raw);
+        assert!(!ipsec.etherip);
+        assert!(!ipsec.default_psk);
+    }
+
+    #[test]
+    fn test_etherip_clients() {
+        let src = r#"ISAKMP Phase 1 ID,Virtual Hub Name,User Name
+router1.example.com,HUB1,router1
+*,HUB2,default"#;
+
+        let clients = SoftEtherReader::decode_etherip_clients(src.as_bytes()).unwrap();
+        assert_eq!(clients.len(), 2);
+        assert_eq!(clients[0].id, String::from("router1.example.com"));
+        assert_eq!(clients[0].hub, String::from("HUB1"));
+        assert_eq!(clients[0].user, String::from("router1"));
+    }
+
+    #[test]
+    fn test_dynamic_dns() {
+        let src = r#"Item,Value
+Assigned Dynamic DNS Hostname (Full),vpn123456789.softether.net
+Assigned Dynamic DNS Hostname (Hostname),vpn123456789
+DNS Suffix,.softether.net
+Global IPv4 Address,203.0.113.10
+Global IPv6 Address,2001:db8::10"#;
+
+        let ddns = SoftEtherReader::decode_dynamic_dns(src.as_bytes()).unwrap();
+        assert_eq!(ddns.hostname, String::from("vpn123456789.softether.net"));
+        assert_eq!(ddns.ipv4, String::from("203.0.113.10"));
+        assert_eq!(ddns.ipv6, String::from("2001:db8::10"));
+
+        let src = r#"Item,Value
+DNS Suffix,.softether.net
+Global IPv6 Address,None
+Global IPv4 Address,203.0.113.10
+Assigned Dynamic DNS Hostname (Hostname),vpn123456789
+Assigned Dynamic DNS Hostname (Full),vpn123456789.softether.net"#;
+
+        let ddns = SoftEtherReader::decode_dynamic_dns(src.as_bytes()).unwrap();
+        assert_eq!(ddns.hostname, String::from("vpn123456789.softether.net"));
+        assert_eq!(ddns.ipv4, String::from("203.0.113.10"));
+        assert_eq!(ddns.ipv6, String::from(""));
+    }
+
+    #[test]
+    fn test_vpn_azure() {
+        let src = r#"Item,Value
+VPN Azure Function is Enabled,Yes
+Connection to VPN Azure Cloud Server is Established,No
+Hostname of this VPN Server on VPN Azure Service,vpn123456789.vpnazure.net"#;
+
+        let azure = SoftEtherReader::decode_vpn_azure(src.as_bytes()).unwrap();
+        assert!(azure.enabled);
+        assert!(!azure.connected);
+    }
+
+    #[test]
+    fn test_cluster_members() {
+        let src = r#"ID,Type,Connection Started at,Host Name,Points,Number of Sessions,Number of TCP Connections,Number of Operating Virtual Hubs,Using Client Connection License,Using Bridge Connection License
+1,Controller,2020-04-08 (Wed) 09:25:49,vpn1.example.com,100000,12,24,3,0,0
+2,Member,2020-04-08 (Wed) 09:30:00,vpn2.example.com,98500,8,16,3,0,0"#;
+
+        let members = SoftEtherReader::decode_cluster_members(src.as_bytes()).unwrap();
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].id, String::from("1"));
+        assert_eq!(members[0].hostname, String::from("vpn1.example.com"));
+        assert!(members[0].controller);
+        assert_eq!(members[0].connected, 1586337949.0);
+        assert!(!members[1].controller);
+        assert_eq!(members[1].points, 98500.0);
+        assert_eq!(members[1].sessions, 8.0);
+        assert_eq!(members[1].tcp_connections, 16.0);
+        assert_eq!(members[1].hubs, 3.0);
+    }
+
+    #[test]
+    fn test_cluster_member_info() {
+        let src = r#"Item,Value
+Type,Member
+Connection Started at,2020-04-08 (Wed) 09:30:00
+Host Name,vpn2.example.com
+Points,98500
+Max Number of Sessions,4096
+Number of Sessions,8
+Number of TCP Connections,16
+Number of Operating Virtual Hubs,3
+Using Client Connection License,0
+Using Bridge Connection License,0
+Performance Standard Ratio,150
+Public IP Address,203.0.113.20"#;
+
+        let info = SoftEtherReader::decode_cluster_member_info(src.as_bytes()).unwrap();
+        assert_eq!(info.weight, 150.0);
+    }
+
+    #[test]
+    fn test_cluster_connection() {
+        let src = r#"Item,Value
+Controller IP Address,192.168.0.10
+Port Number,443
+Connection Status,Online (Connected)
+Last Error,None
+Connection Started at,2020-04-08 (Wed) 09:25:49"#;
+
+        let connection = SoftEtherReader::decode_cluster_connection(src.as_bytes()).unwrap();
+        assert_eq!(connection.controller, String::from("192.168.0.10"));
+        assert!(connection.online);
+    }
+
+    #[test]
+    fn test_hub_access_lists() {
+        let src = r#"ID,Action,Status,Priority,Memo,Contents
+1,Pass,Enabled,100,allow dns,"(ipv4) Protocol=UDP, DstPort=53"
+2,Discard,Disabled,1000,deny smb,"(ipv4) Protocol=TCP, DstPort=445""#;
+
+        let access_lists = SoftEtherReader::decode_hub_access_lists(src.as_bytes()).unwrap();
+        assert_eq!(access_lists.len(), 2);
+        assert_eq!(access_lists[0].id, String::from("1"));
+        assert_eq!(access_lists[0].action, String::from("pass"));
+        assert!(access_lists[0].enabled);
+        assert_eq!(access_lists[0].priority, String::from("100"));
+        assert_eq!(access_lists[1].action, String::from("discard"));
+        assert!(!access_lists[1].enabled);
+    }
+
+    #[test]
+    fn test_hub_groups() {
+        let src = r#"Group Name,Full Name,Description,Number of Users
+sales,Sales Department,,12
+dev,,,3"#;
+
+        let groups = SoftEtherReader::decode_hub_groups(src.as_bytes()).unwrap();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].name, String::from("sales"));
+        assert_eq!(groups[0].users, 12.0);
+        assert_eq!(groups[1].name, String::from("dev"));
+        assert_eq!(groups[1].users, 3.0);
+    }
+
+    #[test]
+    fn test_routers() {
+        let src = r#"Virtual Layer 3 Switch Name,Status,Number of Interfaces,Number of Routing Tables
+L3SW1,Operating,2,1
+L3SW2,Stopped,0,0
+L3SW3,Start (Error),1,0"#;
+
+        let routers = SoftEtherReader::decode_routers(src.as_bytes()).unwrap();
+        assert_eq!(routers.len(), 3);
+        assert_eq!(routers[0].name, String::from("L3SW1"));
+        assert_eq!(routers[0].status, String::from("operating"));
+        assert_eq!(routers[0].interfaces, 2.0);
+        assert_eq!(routers[0].routes, 1.0);
+        assert_eq!(routers[1].status, String::from("stopped"));
+        assert_eq!(routers[2].status, String::from("error"));
+    }
+
+    #[test]
+    fn test_connection_list() {
+        let src = r#"Connection Name,Connection Source,Connection Start,Type
+CID-1,192.168.0.10:50123,2020-04-08 (Wed) 09:25:49,VPN Client
+CID-2,198.51.100.20:40000,2020-04-08 (Wed) 09:30:00,Initializing"#;
+
+        let connections = SoftEtherReader::decode_connection_list(src.as_bytes()).unwrap();
+        assert_eq!(connections.len(), 2);
+        assert_eq!(connections[0].connected, 1586337949.0);
+        assert_eq!(connections[0].connection_type, String::from("VPN Client"));
+        assert_eq!(connections[1].connection_type, String::from("Initializing"));
+    }
+
+    #[test]
+    fn test_server_info() {
+        let src = r#"Item,Value
+Product Name,SoftEther VPN Server (64 bit)
+Version,Version 4.34 Build 9745   (English)
+Build,Compiled 2020/07/05 13:39:07 by buildsan at crosswin
+Server ID,0
+Server Type,Standalone Server
+OS Type,Linux
+OS Product Name,Linux
+OS Vendor,Unknown Vendor
+OS Version,Unknown Linux Version"#;
+
+        let info = SoftEtherReader::decode_server_info(src.as_bytes()).unwrap();
+        assert_eq!(info.product, String::from("SoftEther VPN Server (64 bit)"));
+        assert_eq!(info.version, String::from("4.34"));
+        assert_eq!(info.build, String::from("9745"));
+        assert_eq!(info.os, String::from("Linux"));
+        assert_eq!(info.server_type, String::from("Standalone Server"));
+    }
+
+    #[test]
+    fn test_license() {
+        let src = r#"Item,Value
+Product Edition Name,PacketiX VPN Server 4.0 Enterprise Edition
+Server ID (For License Issuance),12345-67890-12345-67890-12345
+Product License Expiration Date,2020-12-31 (Thu) 23:59:59"#;
+
+        let license = SoftEtherReader::decode_license(src.as_bytes()).unwrap();
+        assert_eq!(
+            license.edition,
+            String::from("PacketiX VPN Server 4.0 Enterprise Edition")
+        );
+        assert_eq!(license.expires, Some(1609459199.0));
+
+        let src = r#"Item,Value
+Product Edition Name,PacketiX VPN Server 4.0 Enterprise Edition
+Product License Expiration Date,No Expiration"#;
+
+        let license = SoftEtherReader::decode_license(src.as_bytes()).unwrap();
+        assert_eq!(license.expires, None);
+    }
+
+    #[test]
+    fn test_caps() {
+        let src = r#"Item,Value
+Maximum Number of Virtual Hubs,4096
+Maximum Number of Sessions,4096
+Maximum Number of Users per Virtual Hub,10000
+Support Clustering,Yes
+Support Syslog,No
+Support VPN Client Connection Licenses,Yes
+b_support_layer3,Yes"#;
+
+        let caps = SoftEtherReader::decode_caps(src.as_bytes()).unwrap();
+        assert_eq!(caps.max_sessions, 4096.0);
+        assert_eq!(caps.max_users, 10000.0);
+        assert_eq!(caps.flags.len(), 3);
+        assert_eq!(caps.flags[0].0, String::from("cluster"));
+        assert!(caps.flags[0].1);
+        assert_eq!(caps.flags[1].0, String::from("syslog"));
+        assert!(!caps.flags[1].1);
+        assert_eq!(caps.flags[2].0, String::from("l3_switch"));
+        assert!(caps.flags[2].1);
+    }
+
+    #[test]
+    fn test_server_cipher() {
+        let src = r#"ServerCipherGet command - Get the Encrypted Algorithm Used for VPN Communication.
+Currently Used Encryption Algorithm:
+ AES128-SHA
+
+Supported Encryption Algorithms:
+ RC4-MD5
+ RC4-SHA
+ AES128-SHA"#;
+
+        let cipher = SoftEtherReader::decode_server_cipher(src.as_bytes()).unwrap();
+        assert_eq!(cipher, String::from("AES128-SHA"));
+    }
+
+    #[test]
+    fn test_keep_alive() {
+        let src = r#"Item,Value
+Host Name,keepalive.softether.org
+Port Number,80
+Packet Send Interval (Seconds),50
+Protocol,UDP
+Enabled,Yes"#;
+
+        let keep = SoftEtherReader::decode_keep_alive(src.as_bytes()).unwrap();
+        assert_eq!(keep.host, String::from("keepalive.softether.org"));
+        assert_eq!(keep.port, String::from("80"));
+        assert_eq!(keep.interval, 50.0);
+        assert_eq!(keep.protocol, String::from("udp"));
+        assert!(keep.enabled);
+
+        let src = r#"Item,Value
+Enabled,No
+Protocol,TCP
+Host Name,keepalive.example.com
+Port Number,443"#;
+
+        let keep = SoftEtherReader::decode_keep_alive(src.as_bytes()).unwrap();
+        assert_eq!(keep.host, String::from("keepalive.example.com"));
+        assert_eq!(keep.port, String::from("443"));
+        assert_eq!(keep.interval, 0.0);
+        assert_eq!(keep.protocol, String::from("tcp"));
+        assert!(!keep.enabled);
+    }
+
+    #[test]
+    fn test_hub_radius() {
+        let src = r#"Item,Value
+Use RADIUS Server,Yes
+RADIUS Server Host Name,radius.example.com
+RADIUS Port Number,1812
+Retry Interval,500"#;
+
+        let radius = SoftEtherReader::decode_hub_radius(src.as_bytes()).unwrap();
+        assert!(radius.enabled);
+        assert_eq!(radius.host, String::from("radius.example.com"));
+        assert_eq!(radius.port, String::from("1812"));
+
+        let src = r#"Item,Value
+Use RADIUS Server,No"#;
+
+        let radius = SoftEtherReader::decode_hub_radius(src.as_bytes()).unwrap();
+        assert!(!radius.enabled);
+        assert_eq!(radius.host, String::from(""));
+
+        let src = r#"Item,Value
+Shared Secret,s3cr3t-radius-key
+RADIUS Port Number,1645
+RADIUS Server Host Name,radius2.example.com
+Use RADIUS Server,Yes
+Retry Interval,500"#;
+
+        let radius = SoftEtherReader::decode_hub_radius(src.as_bytes()).unwrap();
+        assert!(radius.enabled);
+        assert_eq!(radius.host, String::from("radius2.example.com"));
+        assert_eq!(radius.port, String::from("1645"));
+        assert!(!format!("{:?}", radius).contains("s3cr3t-radius-key"));
+    }
 }